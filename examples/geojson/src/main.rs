@@ -1,4 +1,5 @@
 use leptos::prelude::*;
+use leptos_cesium::bindings::Entity as CesiumEntity;
 use leptos_cesium::prelude::*;
 
 /// Helper component to clear data sources when a condition is true
@@ -38,6 +39,14 @@ fn App() -> impl IntoView {
     // Styling options
     let (use_custom_style, set_use_custom_style) = signal(true);
     let (clamp_to_ground, set_clamp_to_ground) = signal(false);
+    let (cluster_points, set_cluster_points) = signal(true);
+
+    // Show the number of cities in each cluster
+    let label_city_cluster = move |entities: Vec<CesiumEntity>, cluster: Cluster| {
+        let label = cluster.label();
+        label.set_text(&format!("{} cities", entities.len()));
+        label.set_fill_color(&Color::white());
+    };
 
     // Button handlers
     let on_countries = move |_| {
@@ -96,6 +105,13 @@ fn App() -> impl IntoView {
                         checked=clamp_to_ground
                         on:change=move |ev| set_clamp_to_ground.set(event_target_checked(&ev))
                     />
+
+                    <label>"Cluster Points:"</label>
+                    <input
+                        type="checkbox"
+                        checked=cluster_points
+                        on:change=move |ev| set_cluster_points.set(event_target_checked(&ev))
+                    />
                 </div>
             </div>
 
@@ -132,6 +148,15 @@ fn App() -> impl IntoView {
                                         marker_color=Color::red()
                                         marker_size=24.0
                                         clamp_to_ground=Some(clamp)
+                                        clustering=Signal::derive(move || {
+                                            cluster_points.get().then(|| {
+                                                ClusterOptions::new()
+                                                    .pixel_range(40.0)
+                                                    .minimum_cluster_size(3)
+                                                    .expand_on_click(true)
+                                            })
+                                        })
+                                        on_cluster=label_city_cluster
                                     />
                                 }
                             } else {
@@ -1,6 +1,16 @@
 use leptos::prelude::*;
 use leptos_cesium::prelude::*;
 
+/// Serve imagery from local tiles so the viewer runs without network access
+const LOCAL_TILES: bool = cfg!(feature = "local-tiles");
+
+/// Natural Earth II tiles shipped with the Cesium build. Point this at your own tile directory
+/// (copied into `public/`) to use other imagery.
+const LOCAL_TILES_URL: &str = "Cesium/Assets/Textures/NaturalEarthII/{z}/{x}/{reverseY}.jpg";
+
+/// Point cloud fixture next to the Statue of Liberty, served from `public/tiles`
+const LOCAL_TILESET_URL: &str = "tiles/points/tileset.json";
+
 fn main() {
     console_error_panic_hook::set_once();
     leptos::mount::mount_to_body(App);
@@ -10,14 +20,26 @@ fn main() {
 fn App() -> impl IntoView {
     let ion_token = option_env!("CESIUM_ION_TOKEN").map(|s| s.to_string());
 
+    let local_imagery = LOCAL_TILES.then(|| {
+        let provider = ImageryProvider::UrlTemplate(
+            UrlTemplateOptions::new(LOCAL_TILES_URL)
+                .tiling_scheme(TilingScheme::Geographic)
+                .maximum_level(2)
+                .credit("Natural Earth II"),
+        );
+        view! { <ImageryLayer provider=provider /> }
+    });
+
     view! {
         <ViewerContainer
             ion_token=ion_token
             animation=false
             timeline=false
+            base_layer=!LOCAL_TILES
             class="cesium-viewer".to_string()
             style="width: 100%; height: 100%;".to_string()
         >
+            {local_imagery}
             <Entity
                 name=Some("Statue of Liberty".to_string())
                 position=Some(Cartesian3::from_degrees(-74.0445, 40.6892, 150.0))
@@ -27,6 +49,10 @@ fn App() -> impl IntoView {
                     color=Some(Color::red())
                 />
             </Entity>
+            <Tileset3D
+                url=LOCAL_TILESET_URL.to_string()
+                style=TileStyle::new().point_size(4.0)
+            />
         </ViewerContainer>
     }
 }
@@ -0,0 +1,149 @@
+//! Cesium fog, sky and celestial body bindings
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Fog blending distant terrain into the atmosphere
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Fog)]
+    pub type Fog;
+
+    #[wasm_bindgen(method, getter, js_name = enabled)]
+    pub fn enabled(this: &Fog) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enabled)]
+    pub fn set_enabled(this: &Fog, value: bool);
+
+    /// Scalar multiplied with the fog density
+    #[wasm_bindgen(method, getter, js_name = density)]
+    pub fn density(this: &Fog) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = density)]
+    pub fn set_density(this: &Fog, value: f64);
+
+    /// Minimum brightness of the fog color, so it doesn't go black at night
+    #[wasm_bindgen(method, getter, js_name = minimumBrightness)]
+    pub fn minimum_brightness(this: &Fog) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = minimumBrightness)]
+    pub fn set_minimum_brightness(this: &Fog, value: f64);
+
+    /// Star background behind the globe
+    #[wasm_bindgen(js_namespace = Cesium, js_name = SkyBox)]
+    pub type SkyBox;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &SkyBox, value: bool);
+
+    /// Atmosphere drawn around the limb of the globe
+    #[wasm_bindgen(js_namespace = Cesium, js_name = SkyAtmosphere)]
+    pub type SkyAtmosphere;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &SkyAtmosphere, value: bool);
+
+    /// The sun
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Sun)]
+    pub type Sun;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &Sun, value: bool);
+
+    /// The moon
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Moon)]
+    pub type Moon;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &Moon, value: bool);
+}
+
+// Cesium's `Fog` defaults, restored for settings left unset
+#[cfg(target_arch = "wasm32")]
+const DEFAULT_FOG_DENSITY: f64 = 6.0e-4;
+#[cfg(target_arch = "wasm32")]
+const DEFAULT_FOG_MINIMUM_BRIGHTNESS: f64 = 0.03;
+
+/// Fog settings for the scene
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let fog = FogOptions::new().density(2.0e-4).minimum_brightness(0.05);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FogOptions {
+    enabled: bool,
+    density: Option<f64>,
+    minimum_brightness: Option<f64>,
+}
+
+impl Default for FogOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            density: None,
+            minimum_brightness: None,
+        }
+    }
+}
+
+impl FogOptions {
+    /// Create fog settings with fog enabled and Cesium defaults otherwise
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fog turned off
+    pub fn disabled() -> Self {
+        Self::default().enabled(false)
+    }
+
+    /// Enable or disable fog (default: true)
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Set the fog density scalar (default: 6.0e-4)
+    pub fn density(mut self, density: f64) -> Self {
+        self.density = Some(density);
+        self
+    }
+
+    /// Set the minimum brightness of the fog color (default: 0.03)
+    pub fn minimum_brightness(mut self, brightness: f64) -> Self {
+        self.minimum_brightness = Some(brightness);
+        self
+    }
+
+    /// Apply the settings to the scene's fog, restoring Cesium's defaults for unset values
+    #[cfg(target_arch = "wasm32")]
+    pub fn apply(&self, fog: &Fog) {
+        fog.set_enabled(self.enabled);
+        fog.set_density(self.density.unwrap_or(DEFAULT_FOG_DENSITY));
+        fog.set_minimum_brightness(
+            self.minimum_brightness
+                .unwrap_or(DEFAULT_FOG_MINIMUM_BRIGHTNESS),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fog_builder() {
+        let fog = FogOptions::new().density(2.0e-4).minimum_brightness(0.05);
+        assert!(fog.enabled);
+        assert_eq!(fog.density, Some(2.0e-4));
+        assert_eq!(fog.minimum_brightness, Some(0.05));
+    }
+
+    #[test]
+    fn disabled_fog_keeps_cesium_defaults() {
+        assert_eq!(FogOptions::disabled(), FogOptions::new().enabled(false));
+        assert!(!FogOptions::disabled().enabled);
+        assert_eq!(FogOptions::disabled().density, None);
+    }
+}
@@ -3,6 +3,11 @@
 use crate::bindings::coordinates::Cartesian3;
 use wasm_bindgen::prelude::*;
 
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{Camera, Viewer};
+#[cfg(target_arch = "wasm32")]
+use crate::core::CesiumError;
+
 // ============================================================================
 // HeadingPitchRoll
 // ============================================================================
@@ -91,6 +96,101 @@ extern "C" {
 // Camera Options Builders
 // ============================================================================
 
+/// Easing of a camera flight, mirrors `Cesium.EasingFunction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EasingFunction {
+    Linear,
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuarticIn,
+    QuarticOut,
+    QuarticInOut,
+    QuinticIn,
+    QuinticOut,
+    QuinticInOut,
+    SinusoidalIn,
+    SinusoidalOut,
+    SinusoidalInOut,
+    ExponentialIn,
+    ExponentialOut,
+    ExponentialInOut,
+    CircularIn,
+    CircularOut,
+    CircularInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+impl EasingFunction {
+    /// Name of the function on `Cesium.EasingFunction`
+    pub fn js_name(self) -> &'static str {
+        match self {
+            Self::Linear => "LINEAR_NONE",
+            Self::QuadraticIn => "QUADRATIC_IN",
+            Self::QuadraticOut => "QUADRATIC_OUT",
+            Self::QuadraticInOut => "QUADRATIC_IN_OUT",
+            Self::CubicIn => "CUBIC_IN",
+            Self::CubicOut => "CUBIC_OUT",
+            Self::CubicInOut => "CUBIC_IN_OUT",
+            Self::QuarticIn => "QUARTIC_IN",
+            Self::QuarticOut => "QUARTIC_OUT",
+            Self::QuarticInOut => "QUARTIC_IN_OUT",
+            Self::QuinticIn => "QUINTIC_IN",
+            Self::QuinticOut => "QUINTIC_OUT",
+            Self::QuinticInOut => "QUINTIC_IN_OUT",
+            Self::SinusoidalIn => "SINUSOIDAL_IN",
+            Self::SinusoidalOut => "SINUSOIDAL_OUT",
+            Self::SinusoidalInOut => "SINUSOIDAL_IN_OUT",
+            Self::ExponentialIn => "EXPONENTIAL_IN",
+            Self::ExponentialOut => "EXPONENTIAL_OUT",
+            Self::ExponentialInOut => "EXPONENTIAL_IN_OUT",
+            Self::CircularIn => "CIRCULAR_IN",
+            Self::CircularOut => "CIRCULAR_OUT",
+            Self::CircularInOut => "CIRCULAR_IN_OUT",
+            Self::ElasticIn => "ELASTIC_IN",
+            Self::ElasticOut => "ELASTIC_OUT",
+            Self::ElasticInOut => "ELASTIC_IN_OUT",
+            Self::BackIn => "BACK_IN",
+            Self::BackOut => "BACK_OUT",
+            Self::BackInOut => "BACK_IN_OUT",
+            Self::BounceIn => "BOUNCE_IN",
+            Self::BounceOut => "BOUNCE_OUT",
+            Self::BounceInOut => "BOUNCE_IN_OUT",
+        }
+    }
+
+    /// The Cesium easing function
+    #[cfg(target_arch = "wasm32")]
+    pub fn to_js(self) -> JsValue {
+        use js_sys::{Reflect, global};
+
+        Reflect::get(&global(), &JsValue::from_str("Cesium"))
+            .and_then(|cesium| Reflect::get(&cesium, &JsValue::from_str("EasingFunction")))
+            .and_then(|easing| Reflect::get(&easing, &JsValue::from_str(self.js_name())))
+            .expect("Cesium.EasingFunction to exist")
+    }
+}
+
+/// How a camera flight ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlightOutcome {
+    /// The camera reached its destination
+    Completed,
+    /// The flight was interrupted by user input or another flight
+    Cancelled,
+}
+
 /// Builder for Camera.flyTo() options
 #[cfg(target_arch = "wasm32")]
 pub struct FlyToOptions {
@@ -100,6 +200,10 @@ pub struct FlyToOptions {
     complete: Option<js_sys::Function>,
     cancel: Option<js_sys::Function>,
     offset: Option<HeadingPitchRange>,
+    easing_function: Option<EasingFunction>,
+    maximum_height: Option<f64>,
+    pitch_adjust_height: Option<f64>,
+    fly_over_longitude: Option<f64>,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -113,6 +217,10 @@ impl FlyToOptions {
             complete: None,
             cancel: None,
             offset: None,
+            easing_function: None,
+            maximum_height: None,
+            pitch_adjust_height: None,
+            fly_over_longitude: None,
         }
     }
 
@@ -134,6 +242,30 @@ impl FlyToOptions {
         self
     }
 
+    /// Set the easing of the flight
+    pub fn easing_function(mut self, easing_function: EasingFunction) -> Self {
+        self.easing_function = Some(easing_function);
+        self
+    }
+
+    /// Set the maximum height in meters at the peak of the flight
+    pub fn maximum_height(mut self, maximum_height: f64) -> Self {
+        self.maximum_height = Some(maximum_height);
+        self
+    }
+
+    /// Look down at the destination while flying above this height in meters
+    pub fn pitch_adjust_height(mut self, pitch_adjust_height: f64) -> Self {
+        self.pitch_adjust_height = Some(pitch_adjust_height);
+        self
+    }
+
+    /// Always fly over this longitude in radians, e.g. to avoid crossing the antimeridian
+    pub fn fly_over_longitude(mut self, fly_over_longitude: f64) -> Self {
+        self.fly_over_longitude = Some(fly_over_longitude);
+        self
+    }
+
     /// Set callback to execute when flight completes
     pub fn on_complete(mut self, callback: js_sys::Function) -> Self {
         self.complete = Some(callback);
@@ -202,6 +334,38 @@ impl FlyToOptions {
             );
         }
 
+        if let Some(easing_function) = self.easing_function {
+            let _ = Reflect::set(
+                &options,
+                &JsValue::from_str("easingFunction"),
+                &easing_function.to_js(),
+            );
+        }
+
+        if let Some(maximum_height) = self.maximum_height {
+            let _ = Reflect::set(
+                &options,
+                &JsValue::from_str("maximumHeight"),
+                &JsValue::from_f64(maximum_height),
+            );
+        }
+
+        if let Some(pitch_adjust_height) = self.pitch_adjust_height {
+            let _ = Reflect::set(
+                &options,
+                &JsValue::from_str("pitchAdjustHeight"),
+                &JsValue::from_f64(pitch_adjust_height),
+            );
+        }
+
+        if let Some(fly_over_longitude) = self.fly_over_longitude {
+            let _ = Reflect::set(
+                &options,
+                &JsValue::from_str("flyOverLongitude"),
+                &JsValue::from_f64(fly_over_longitude),
+            );
+        }
+
         // Optional complete callback
         if let Some(complete) = self.complete {
             let _ = Reflect::set(&options, &JsValue::from_str("complete"), &complete);
@@ -280,3 +444,214 @@ impl SetViewOptions {
         JsValue::from(options)
     }
 }
+
+// ============================================================================
+// Awaitable Flights
+// ============================================================================
+
+/// Fly the camera and wait for the flight to end
+///
+/// Callbacks set with `on_complete`/`on_cancel` still run. Fails if Cesium rejects the
+/// options, or with `CesiumError::ViewerUnavailable` if the viewer is already destroyed.
+/// Cesium never ends the flights of a viewer destroyed mid-flight, so the future then stays
+/// pending; components stop caring about it on cleanup.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// for stop in stops {
+///     if fly_to(&viewer, FlyToOptions::new(stop)).await? == FlightOutcome::Cancelled {
+///         break;
+///     }
+/// }
+/// ```
+#[cfg(target_arch = "wasm32")]
+pub async fn fly_to(
+    viewer: &Viewer,
+    mut options: FlyToOptions,
+) -> Result<FlightOutcome, CesiumError> {
+    let on_complete = options.complete.take();
+    let on_cancel = options.cancel.take();
+    let options = options.build();
+
+    await_flight(
+        viewer,
+        on_complete,
+        on_cancel,
+        |camera, complete, cancel| {
+            use js_sys::Reflect;
+
+            Reflect::set(&options, &JsValue::from_str("complete"), &complete)?;
+            Reflect::set(&options, &JsValue::from_str("cancel"), &cancel)?;
+            camera.try_fly_to(&options)
+        },
+    )
+    .await
+}
+
+/// Fly the camera to a position where the whole sphere is visible and wait for the flight
+/// to end
+#[cfg(target_arch = "wasm32")]
+pub async fn fly_to_bounding_sphere(
+    viewer: &Viewer,
+    sphere: &BoundingSphere,
+    offset: Option<HeadingPitchRange>,
+    duration: Option<f64>,
+) -> Result<FlightOutcome, CesiumError> {
+    await_flight(viewer, None, None, |camera, complete, cancel| {
+        use js_sys::{Object, Reflect};
+
+        let options = Object::new();
+        if let Some(offset) = offset {
+            Reflect::set(&options, &JsValue::from_str("offset"), &offset)?;
+        }
+        if let Some(duration) = duration {
+            Reflect::set(
+                &options,
+                &JsValue::from_str("duration"),
+                &JsValue::from_f64(duration),
+            )?;
+        }
+        Reflect::set(&options, &JsValue::from_str("complete"), &complete)?;
+        Reflect::set(&options, &JsValue::from_str("cancel"), &cancel)?;
+        camera.fly_to_bounding_sphere(sphere, &options)
+    })
+    .await
+}
+
+/// Fly the camera to its home view and wait for the flight to end
+///
+/// The home view is the one `Camera.flyHome` uses: `Camera.DEFAULT_VIEW_RECTANGLE`, zoomed
+/// out by `Camera.DEFAULT_VIEW_FACTOR` on the globe. A running morph is completed first.
+#[cfg(target_arch = "wasm32")]
+pub async fn fly_home(viewer: &Viewer, duration: f64) -> Result<FlightOutcome, CesiumError> {
+    await_flight(viewer, None, None, |camera, complete, cancel| {
+        use js_sys::Reflect;
+
+        let options = home_flight_options(viewer)?;
+        Reflect::set(
+            &options,
+            &JsValue::from_str("duration"),
+            &JsValue::from_f64(duration),
+        )?;
+        Reflect::set(&options, &JsValue::from_str("complete"), &complete)?;
+        Reflect::set(&options, &JsValue::from_str("cancel"), &cancel)?;
+        camera.try_fly_to(&options)
+    })
+    .await
+}
+
+/// Internal helper building the `flyTo` options of the home view in the current scene mode
+///
+/// `Camera.flyHome` takes no completion callbacks, so this mirrors its implementation as of
+/// Cesium 1.135 (the version `scripts/sync_cesium_assets.sh` installs). Check it against
+/// `Camera.prototype.flyHome` when upgrading Cesium.
+#[cfg(target_arch = "wasm32")]
+fn home_flight_options(viewer: &Viewer) -> Result<js_sys::Object, JsValue> {
+    use crate::bindings::{Matrix4, Rectangle, SceneMode};
+    use js_sys::{Object, Reflect, global};
+
+    let get = |target: &JsValue, key: &str| Reflect::get(target, &JsValue::from_str(key));
+    let set = |target: &JsValue, key: &str, value: &JsValue| {
+        Reflect::set(target, &JsValue::from_str(key), value).map(|_| ())
+    };
+
+    let scene = viewer.scene();
+    let mode = SceneMode::from_cesium(scene.mode()).unwrap_or_else(|| {
+        scene.complete_morph();
+        SceneMode::from_cesium(scene.mode()).unwrap_or_default()
+    });
+    let camera_class = get(&get(&global(), "Cesium")?, "Camera")?;
+    let rectangle: Rectangle = get(&camera_class, "DEFAULT_VIEW_RECTANGLE")?.dyn_into()?;
+
+    let options = Object::new();
+    set(&options, "endTransform", &Matrix4::identity())?;
+    match mode {
+        SceneMode::Scene2D => set(&options, "destination", &rectangle)?,
+        SceneMode::Scene3D => {
+            let factor = get(&camera_class, "DEFAULT_VIEW_FACTOR")?
+                .as_f64()
+                .unwrap_or_default();
+            let fitted = viewer.camera().get_rectangle_camera_coordinates(&rectangle);
+            let scale = 1.0 + factor;
+            let destination =
+                Cartesian3::new(fitted.x() * scale, fitted.y() * scale, fitted.z() * scale);
+            set(&options, "destination", &destination)?;
+        }
+        SceneMode::ColumbusView => {
+            // Looking north at the map from 5 radii away, 45 degrees above it
+            let ellipsoid = get(&get(&scene, "mapProjection")?, "ellipsoid")?;
+            let distance = 5.0
+                * get(&ellipsoid, "maximumRadius")?
+                    .as_f64()
+                    .unwrap_or_default();
+            let offset = distance * std::f64::consts::FRAC_1_SQRT_2;
+            set(
+                &options,
+                "destination",
+                &Cartesian3::new(0.0, -offset, offset),
+            )?;
+
+            let orientation = Object::new();
+            set(&orientation, "heading", &JsValue::from_f64(0.0))?;
+            set(
+                &orientation,
+                "pitch",
+                &JsValue::from_f64(-std::f64::consts::FRAC_PI_4),
+            )?;
+            set(&orientation, "roll", &JsValue::from_f64(0.0))?;
+            set(&options, "orientation", &orientation)?;
+            set(&options, "convert", &JsValue::FALSE)?;
+        }
+    }
+    Ok(options)
+}
+
+/// Internal helper resolving a flight to its outcome
+///
+/// `start` receives the camera and the `complete` and `cancel` callbacks to hand to Cesium;
+/// the optional caller callbacks run before the outcome is reported.
+#[cfg(target_arch = "wasm32")]
+async fn await_flight(
+    viewer: &Viewer,
+    mut on_complete: Option<js_sys::Function>,
+    mut on_cancel: Option<js_sys::Function>,
+    start: impl FnOnce(&Camera, js_sys::Function, js_sys::Function) -> Result<(), JsValue>,
+) -> Result<FlightOutcome, CesiumError> {
+    use js_sys::{Function, Promise};
+
+    if viewer.is_destroyed() {
+        return Err(CesiumError::ViewerUnavailable);
+    }
+
+    let settle = |resolve: &Function, callback: Option<Function>, completed: bool| {
+        let resolve = resolve.clone();
+        Closure::wrap(Box::new(move || {
+            if let Some(callback) = &callback {
+                let _ = callback.call0(&JsValue::NULL);
+            }
+            let _ = resolve.call1(&JsValue::NULL, &JsValue::from_bool(completed));
+        }) as Box<dyn FnMut()>)
+        .into_js_value()
+        .unchecked_into::<Function>()
+    };
+
+    let camera = viewer.camera();
+    let mut start = Some(start);
+    let mut started = Ok(());
+    let flight = Promise::new(&mut |resolve, _reject| {
+        if let Some(start) = start.take() {
+            let complete = settle(&resolve, on_complete.take(), true);
+            let cancel = settle(&resolve, on_cancel.take(), false);
+            started = start(&camera, complete, cancel);
+        }
+    });
+    started?;
+
+    let completed = wasm_bindgen_futures::JsFuture::from(flight).await?;
+    Ok(if completed.is_truthy() {
+        FlightOutcome::Completed
+    } else {
+        FlightOutcome::Cancelled
+    })
+}
@@ -0,0 +1,209 @@
+//! Bindings for `Cesium.ScreenSpaceCameraController`, which turns user input into camera motion.
+
+use wasm_bindgen::prelude::*;
+
+use crate::bindings::KeyboardEventModifier;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Handles mouse, wheel and touch input for the scene's camera
+    #[wasm_bindgen(js_namespace = Cesium, js_name = ScreenSpaceCameraController)]
+    pub type ScreenSpaceCameraController;
+
+    /// Master switch for all camera input
+    #[wasm_bindgen(method, getter, js_name = enableInputs)]
+    pub fn enable_inputs(this: &ScreenSpaceCameraController) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enableInputs)]
+    pub fn set_enable_inputs(this: &ScreenSpaceCameraController, value: bool);
+
+    #[wasm_bindgen(method, getter, js_name = enableRotate)]
+    pub fn enable_rotate(this: &ScreenSpaceCameraController) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enableRotate)]
+    pub fn set_enable_rotate(this: &ScreenSpaceCameraController, value: bool);
+
+    #[wasm_bindgen(method, getter, js_name = enableTranslate)]
+    pub fn enable_translate(this: &ScreenSpaceCameraController) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enableTranslate)]
+    pub fn set_enable_translate(this: &ScreenSpaceCameraController, value: bool);
+
+    #[wasm_bindgen(method, getter, js_name = enableZoom)]
+    pub fn enable_zoom(this: &ScreenSpaceCameraController) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enableZoom)]
+    pub fn set_enable_zoom(this: &ScreenSpaceCameraController, value: bool);
+
+    #[wasm_bindgen(method, getter, js_name = enableTilt)]
+    pub fn enable_tilt(this: &ScreenSpaceCameraController) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enableTilt)]
+    pub fn set_enable_tilt(this: &ScreenSpaceCameraController, value: bool);
+
+    #[wasm_bindgen(method, getter, js_name = enableLook)]
+    pub fn enable_look(this: &ScreenSpaceCameraController) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enableLook)]
+    pub fn set_enable_look(this: &ScreenSpaceCameraController, value: bool);
+
+    /// Whether the camera is kept above terrain and 3D Tiles
+    #[wasm_bindgen(method, getter, js_name = enableCollisionDetection)]
+    pub fn enable_collision_detection(this: &ScreenSpaceCameraController) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enableCollisionDetection)]
+    pub fn set_enable_collision_detection(this: &ScreenSpaceCameraController, value: bool);
+
+    /// Minimum distance in meters between the camera and the surface
+    #[wasm_bindgen(method, getter, js_name = minimumZoomDistance)]
+    pub fn minimum_zoom_distance(this: &ScreenSpaceCameraController) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = minimumZoomDistance)]
+    pub fn set_minimum_zoom_distance(this: &ScreenSpaceCameraController, value: f64);
+
+    /// Maximum distance in meters between the camera and the surface
+    #[wasm_bindgen(method, getter, js_name = maximumZoomDistance)]
+    pub fn maximum_zoom_distance(this: &ScreenSpaceCameraController) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = maximumZoomDistance)]
+    pub fn set_maximum_zoom_distance(this: &ScreenSpaceCameraController, value: f64);
+
+    #[wasm_bindgen(method, setter, js_name = rotateEventTypes)]
+    fn set_rotate_event_types_raw(this: &ScreenSpaceCameraController, value: &JsValue);
+
+    #[wasm_bindgen(method, setter, js_name = tiltEventTypes)]
+    fn set_tilt_event_types_raw(this: &ScreenSpaceCameraController, value: &JsValue);
+
+    #[wasm_bindgen(method, setter, js_name = zoomEventTypes)]
+    fn set_zoom_event_types_raw(this: &ScreenSpaceCameraController, value: &JsValue);
+
+    #[wasm_bindgen(method, setter, js_name = translateEventTypes)]
+    fn set_translate_event_types_raw(this: &ScreenSpaceCameraController, value: &JsValue);
+
+    #[wasm_bindgen(method, setter, js_name = lookEventTypes)]
+    fn set_look_event_types_raw(this: &ScreenSpaceCameraController, value: &JsValue);
+}
+
+/// Mirrors `Cesium.CameraEventType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CameraEventType {
+    LeftDrag = 0,
+    RightDrag = 1,
+    MiddleDrag = 2,
+    Wheel = 3,
+    Pinch = 4,
+}
+
+/// An input that triggers a camera action, optionally only while a modifier key is held
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CameraInput {
+    pub event: CameraEventType,
+    pub modifier: Option<KeyboardEventModifier>,
+}
+
+impl CameraInput {
+    /// Input without a modifier key
+    pub fn new(event: CameraEventType) -> Self {
+        Self {
+            event,
+            modifier: None,
+        }
+    }
+
+    /// Input while `modifier` is held, e.g. Ctrl + left drag
+    pub fn with_modifier(event: CameraEventType, modifier: KeyboardEventModifier) -> Self {
+        Self {
+            event,
+            modifier: Some(modifier),
+        }
+    }
+
+    /// The input as Cesium expects it: the bare event type, or an object with the modifier
+    #[cfg(target_arch = "wasm32")]
+    fn to_js(self) -> JsValue {
+        use js_sys::{Object, Reflect};
+
+        let Some(modifier) = self.modifier else {
+            return JsValue::from(self.event as u32);
+        };
+        let input = Object::new();
+        let _ = Reflect::set(
+            &input,
+            &JsValue::from_str("eventType"),
+            &JsValue::from(self.event as u32),
+        );
+        let _ = Reflect::set(
+            &input,
+            &JsValue::from_str("modifier"),
+            &JsValue::from(modifier as u32),
+        );
+        input.into()
+    }
+}
+
+impl From<CameraEventType> for CameraInput {
+    fn from(event: CameraEventType) -> Self {
+        Self::new(event)
+    }
+}
+
+/// Internal helper converting inputs to Cesium's event type array
+#[cfg(target_arch = "wasm32")]
+fn inputs_to_js(inputs: &[CameraInput]) -> JsValue {
+    inputs
+        .iter()
+        .map(|input| input.to_js())
+        .collect::<js_sys::Array>()
+        .into()
+}
+
+#[cfg(target_arch = "wasm32")]
+impl ScreenSpaceCameraController {
+    /// Inputs that rotate the camera around the globe (default: left drag)
+    pub fn set_rotate_event_types(&self, inputs: &[CameraInput]) {
+        self.set_rotate_event_types_raw(&inputs_to_js(inputs));
+    }
+
+    /// Inputs that tilt the camera (default: middle drag, pinch, Ctrl + left/right drag)
+    pub fn set_tilt_event_types(&self, inputs: &[CameraInput]) {
+        self.set_tilt_event_types_raw(&inputs_to_js(inputs));
+    }
+
+    /// Inputs that zoom the camera (default: right drag, wheel, pinch)
+    pub fn set_zoom_event_types(&self, inputs: &[CameraInput]) {
+        self.set_zoom_event_types_raw(&inputs_to_js(inputs));
+    }
+
+    /// Inputs that pan the camera in 2D and Columbus view (default: left drag)
+    pub fn set_translate_event_types(&self, inputs: &[CameraInput]) {
+        self.set_translate_event_types_raw(&inputs_to_js(inputs));
+    }
+
+    /// Inputs that turn the camera in place (default: Shift + left drag)
+    pub fn set_look_event_types(&self, inputs: &[CameraInput]) {
+        self.set_look_event_types_raw(&inputs_to_js(inputs));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inputs_match_cesium_event_types() {
+        assert_eq!(CameraEventType::LeftDrag as u32, 0);
+        assert_eq!(CameraEventType::Wheel as u32, 3);
+        assert_eq!(KeyboardEventModifier::Ctrl as u32, 1);
+        assert_eq!(
+            CameraInput::with_modifier(CameraEventType::LeftDrag, KeyboardEventModifier::Ctrl),
+            CameraInput {
+                event: CameraEventType::LeftDrag,
+                modifier: Some(KeyboardEventModifier::Ctrl),
+            }
+        );
+        assert_eq!(
+            CameraInput::from(CameraEventType::Wheel),
+            CameraInput::new(CameraEventType::Wheel)
+        );
+    }
+}
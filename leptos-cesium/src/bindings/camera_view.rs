@@ -0,0 +1,186 @@
+//! Serializable camera views for bookmarks and shareable links
+
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{Cartesian3, FlyToOptions, HeadingPitchRoll, SetViewOptions, Viewer};
+
+/// A camera position and orientation in degrees
+///
+/// Serializes with serde for storage (with the `serde` feature), and formats to a compact
+/// `longitude,latitude,height,heading,pitch,roll` string for URLs.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let bookmark = CameraView::capture(&viewer);
+/// let json = serde_json::to_string(&bookmark)?;
+///
+/// // Later
+/// let bookmark: CameraView = serde_json::from_str(&json)?;
+/// bookmark.apply(&viewer, true);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CameraView {
+    /// Longitude in degrees
+    pub longitude: f64,
+    /// Latitude in degrees
+    pub latitude: f64,
+    /// Height above the ellipsoid in meters
+    pub height: f64,
+    /// Heading in degrees, clockwise from north
+    pub heading: f64,
+    /// Pitch in degrees, negative looks down
+    pub pitch: f64,
+    /// Roll in degrees
+    pub roll: f64,
+}
+
+impl CameraView {
+    /// View looking straight down at a position
+    pub fn new(longitude: f64, latitude: f64, height: f64) -> Self {
+        Self {
+            longitude,
+            latitude,
+            height,
+            heading: 0.0,
+            pitch: -90.0,
+            roll: 0.0,
+        }
+    }
+
+    /// Set heading, pitch and roll in degrees
+    pub fn orientation(mut self, heading: f64, pitch: f64, roll: f64) -> Self {
+        self.heading = heading;
+        self.pitch = pitch;
+        self.roll = roll;
+        self
+    }
+
+    /// The viewer camera's current view
+    #[cfg(target_arch = "wasm32")]
+    pub fn capture(viewer: &Viewer) -> Self {
+        let camera = viewer.camera();
+        let position = camera.position_cartographic();
+        Self {
+            longitude: position.longitude_degrees(),
+            latitude: position.latitude_degrees(),
+            height: position.height(),
+            heading: camera.heading().to_degrees(),
+            pitch: camera.pitch().to_degrees(),
+            roll: camera.roll().to_degrees(),
+        }
+    }
+
+    /// Move the viewer camera to this view, animated when `fly` is true
+    #[cfg(target_arch = "wasm32")]
+    pub fn apply(&self, viewer: &Viewer, fly: bool) {
+        let camera = viewer.camera();
+        if fly {
+            camera.fly_to(&self.fly_to_options().build());
+        } else {
+            camera.set_view(
+                &SetViewOptions::new(self.destination())
+                    .orientation(self.heading_pitch_roll())
+                    .build(),
+            );
+        }
+    }
+
+    /// Flight options to this view, to add a duration or easing before flying
+    #[cfg(target_arch = "wasm32")]
+    pub fn fly_to_options(&self) -> FlyToOptions {
+        FlyToOptions::new(self.destination()).orientation(self.heading_pitch_roll())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn destination(&self) -> Cartesian3 {
+        Cartesian3::from_degrees(self.longitude, self.latitude, self.height)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn heading_pitch_roll(&self) -> HeadingPitchRoll {
+        HeadingPitchRoll::new(
+            self.heading.to_radians(),
+            self.pitch.to_radians(),
+            self.roll.to_radians(),
+        )
+    }
+}
+
+/// Formats with enough precision for about 10 cm on the ground
+impl fmt::Display for CameraView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.6},{:.6},{:.1},{:.2},{:.2},{:.2}",
+            self.longitude, self.latitude, self.height, self.heading, self.pitch, self.roll
+        )
+    }
+}
+
+/// Error parsing a `CameraView` from its string form
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("invalid camera view {0:?}, expected longitude,latitude,height,heading,pitch,roll")]
+pub struct ParseCameraViewError(String);
+
+impl FromStr for CameraView {
+    type Err = ParseCameraViewError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseCameraViewError(s.to_string());
+        let values = s
+            .split(',')
+            .map(|value| value.trim().parse::<f64>().ok().filter(|v| v.is_finite()))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(error)?;
+        let [longitude, latitude, height, heading, pitch, roll] = values[..] else {
+            return Err(error());
+        };
+
+        Ok(Self {
+            longitude,
+            latitude,
+            height,
+            heading,
+            pitch,
+            roll,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let view = CameraView::new(-122.4194, 37.7749, 1500.0).orientation(45.0, -30.0, 0.0);
+        let json = serde_json::to_string(&view).unwrap();
+        assert_eq!(serde_json::from_str::<CameraView>(&json).unwrap(), view);
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let view = CameraView::new(8.5417, 47.3769, 2500.5).orientation(12.25, -45.5, 0.0);
+        assert_eq!(
+            view.to_string(),
+            "8.541700,47.376900,2500.5,12.25,-45.50,0.00"
+        );
+        assert_eq!(view.to_string().parse::<CameraView>().unwrap(), view);
+    }
+
+    #[test]
+    fn rejects_malformed_strings() {
+        assert!("1,2,3".parse::<CameraView>().is_err());
+        assert!("1,2,3,4,5,6,7".parse::<CameraView>().is_err());
+        assert!("1,2,x,4,5,6".parse::<CameraView>().is_err());
+        assert!("1,2,NaN,4,5,6".parse::<CameraView>().is_err());
+    }
+}
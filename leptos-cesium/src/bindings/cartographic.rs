@@ -0,0 +1,95 @@
+//! Cesium Cartographic positions (longitude, latitude, height)
+
+use wasm_bindgen::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::Cartesian3;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A position defined by longitude and latitude in radians and height in meters
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Cartographic)]
+    pub type Cartographic;
+
+    /// Create a Cartographic from longitude and latitude in radians and height in meters
+    #[wasm_bindgen(constructor, js_namespace = Cesium, js_class = Cartographic)]
+    pub fn new(longitude: f64, latitude: f64, height: f64) -> Cartographic;
+
+    /// Gets the longitude in radians
+    #[wasm_bindgen(method, getter)]
+    pub fn longitude(this: &Cartographic) -> f64;
+
+    /// Gets the latitude in radians
+    #[wasm_bindgen(method, getter)]
+    pub fn latitude(this: &Cartographic) -> f64;
+
+    /// Gets the height in meters above the ellipsoid
+    #[wasm_bindgen(method, getter)]
+    pub fn height(this: &Cartographic) -> f64;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_height(this: &Cartographic, height: f64);
+}
+
+/// Internal helper using reflection to call a static function on Cesium.Cartographic
+#[cfg(target_arch = "wasm32")]
+fn call_cartographic_static(name: &str, args: &js_sys::Array) -> JsValue {
+    use js_sys::{Function, Reflect, global};
+
+    let cesium = Reflect::get(&global(), &JsValue::from_str("Cesium"))
+        .expect("Cesium global to be available");
+    let cartographic = Reflect::get(&cesium, &JsValue::from_str("Cartographic"))
+        .expect("Cesium.Cartographic to exist");
+    let function: Function = Reflect::get(&cartographic, &JsValue::from_str(name))
+        .ok()
+        .and_then(|f| f.dyn_into().ok())
+        .unwrap_or_else(|| panic!("Cesium.Cartographic.{} to be callable", name));
+    Reflect::apply(&function, &cartographic, args)
+        .unwrap_or_else(|_| panic!("Cesium.Cartographic.{} call to succeed", name))
+}
+
+impl Cartographic {
+    /// Create a Cartographic from longitude and latitude in degrees and height in meters.
+    ///
+    /// Calls Cesium.Cartographic.fromDegrees internally.
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_degrees(longitude: f64, latitude: f64, height: f64) -> Self {
+        call_cartographic_static(
+            "fromDegrees",
+            &js_sys::Array::of3(
+                &JsValue::from_f64(longitude),
+                &JsValue::from_f64(latitude),
+                &JsValue::from_f64(height),
+            ),
+        )
+        .unchecked_into()
+    }
+
+    /// Convert a Cartesian position to Cartographic, or `None` at the center of the ellipsoid.
+    ///
+    /// Calls Cesium.Cartographic.fromCartesian internally.
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_cartesian(cartesian: &Cartesian3) -> Option<Self> {
+        let result = call_cartographic_static("fromCartesian", &js_sys::Array::of1(cartesian));
+        (!result.is_undefined()).then(|| result.unchecked_into())
+    }
+
+    /// Convert to a Cartesian position on the WGS84 ellipsoid.
+    ///
+    /// Calls Cesium.Cartographic.toCartesian internally.
+    #[cfg(target_arch = "wasm32")]
+    pub fn to_cartesian(&self) -> Cartesian3 {
+        call_cartographic_static("toCartesian", &js_sys::Array::of1(self)).unchecked_into()
+    }
+
+    /// Gets the longitude in degrees
+    pub fn longitude_degrees(&self) -> f64 {
+        self.longitude().to_degrees()
+    }
+
+    /// Gets the latitude in degrees
+    pub fn latitude_degrees(&self) -> f64 {
+        self.latitude().to_degrees()
+    }
+}
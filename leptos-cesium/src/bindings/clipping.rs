@@ -0,0 +1,348 @@
+//! Cesium clipping planes and polygons for cutting into tilesets, models and the globe
+
+use crate::bindings::Color;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A plane in Hessian normal form; geometry on the side opposite the normal is clipped
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = ClippingPlane)]
+    pub type ClippingPlane;
+
+    #[wasm_bindgen(constructor, js_namespace = Cesium, js_class = ClippingPlane)]
+    pub fn new(normal: &crate::bindings::Cartesian3, distance: f64) -> ClippingPlane;
+
+    /// A set of clipping planes owned by one tileset, model or globe
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = ClippingPlaneCollection)]
+    pub type ClippingPlaneCollection;
+
+    #[wasm_bindgen(constructor, js_namespace = Cesium, js_class = ClippingPlaneCollection)]
+    pub fn new(options: &JsValue) -> ClippingPlaneCollection;
+
+    /// A polygon on the globe, given by positions on the ellipsoid
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = ClippingPolygon)]
+    pub type ClippingPolygon;
+
+    #[wasm_bindgen(constructor, js_namespace = Cesium, js_class = ClippingPolygon)]
+    pub fn new(options: &JsValue) -> ClippingPolygon;
+
+    /// A set of clipping polygons owned by one tileset or globe
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = ClippingPolygonCollection)]
+    pub type ClippingPolygonCollection;
+
+    #[wasm_bindgen(constructor, js_namespace = Cesium, js_class = ClippingPolygonCollection)]
+    pub fn new(options: &JsValue) -> ClippingPolygonCollection;
+}
+
+/// Description of a `ClippingPlane`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClippingPlaneOptions {
+    /// Unit normal (x, y, z) in the collection's local frame
+    pub normal: (f64, f64, f64),
+    /// Signed distance from the origin along the normal
+    pub distance: f64,
+}
+
+impl ClippingPlaneOptions {
+    /// Create a plane from its unit normal and signed distance
+    pub fn new(normal: (f64, f64, f64), distance: f64) -> Self {
+        Self { normal, distance }
+    }
+
+    /// A horizontal plane `height` meters above the local origin that clips everything above it,
+    /// e.g. to cut a building open at a floor
+    pub fn clip_above(height: f64) -> Self {
+        Self::new((0.0, 0.0, -1.0), height)
+    }
+
+    /// A horizontal plane `height` meters above the local origin that clips everything below it
+    pub fn clip_below(height: f64) -> Self {
+        Self::new((0.0, 0.0, 1.0), -height)
+    }
+}
+
+/// Builder for a `ClippingPlaneCollection`
+///
+/// Planes are positioned relative to the owner: the tileset's root transform, the model's
+/// position, or the globe's fixed frame. Use [`model_matrix`](Self::model_matrix) to move them.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let clipping = ClippingPlanes::new()
+///     .plane(ClippingPlaneOptions::clip_above(12.0))
+///     .edge_color(Color::white())
+///     .edge_width(2.0);
+///
+/// view! { <Tileset3D url=url clipping=Clipping::from(clipping) /> }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClippingPlanes {
+    planes: Vec<ClippingPlaneOptions>,
+    enabled: bool,
+    union_clipping_regions: bool,
+    edge_color: Option<(f64, f64, f64, f64)>,
+    edge_width: Option<f64>,
+    model_matrix: Option<[f64; 16]>,
+}
+
+impl Default for ClippingPlanes {
+    fn default() -> Self {
+        Self {
+            planes: Vec::new(),
+            enabled: true,
+            union_clipping_regions: false,
+            edge_color: None,
+            edge_width: None,
+            model_matrix: None,
+        }
+    }
+}
+
+impl ClippingPlanes {
+    /// Create an empty, enabled collection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a plane
+    pub fn plane(mut self, plane: ClippingPlaneOptions) -> Self {
+        self.planes.push(plane);
+        self
+    }
+
+    /// Set whether clipping is applied (default: true)
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Clip regions outside any plane instead of outside all planes (default: false)
+    pub fn union_clipping_regions(mut self, union: bool) -> Self {
+        self.union_clipping_regions = union;
+        self
+    }
+
+    /// Set the color used to highlight clipped edges (default: white)
+    pub fn edge_color(mut self, color: Color) -> Self {
+        self.edge_color = Some(color.to_rgba());
+        self
+    }
+
+    /// Set the width in pixels of the highlighted clipped edges (default: 0, no highlight)
+    pub fn edge_width(mut self, width: f64) -> Self {
+        self.edge_width = Some(width);
+        self
+    }
+
+    /// Transform the planes by a matrix given as 16 values in column-major order
+    pub fn model_matrix(mut self, matrix: [f64; 16]) -> Self {
+        self.model_matrix = Some(matrix);
+        self
+    }
+
+    /// Build a new collection; each owner needs its own instance
+    #[cfg(target_arch = "wasm32")]
+    pub fn build(&self) -> ClippingPlaneCollection {
+        use crate::bindings::{Cartesian3, Matrix4};
+        use js_sys::{Array, Object, Reflect};
+
+        let options = Object::new();
+        let set = |key: &str, value: &JsValue| {
+            let _ = Reflect::set(&options, &JsValue::from_str(key), value);
+        };
+
+        let planes: Array = self
+            .planes
+            .iter()
+            .map(|plane| {
+                let (x, y, z) = plane.normal;
+                JsValue::from(ClippingPlane::new(
+                    &Cartesian3::new(x, y, z),
+                    plane.distance,
+                ))
+            })
+            .collect();
+        set("planes", &planes);
+        set("enabled", &JsValue::from_bool(self.enabled));
+        set(
+            "unionClippingRegions",
+            &JsValue::from_bool(self.union_clipping_regions),
+        );
+        if let Some((red, green, blue, alpha)) = self.edge_color {
+            set("edgeColor", &Color::new(red, green, blue, alpha).into());
+        }
+        if let Some(width) = self.edge_width {
+            set("edgeWidth", &JsValue::from_f64(width));
+        }
+        if let Some(matrix) = &self.model_matrix {
+            set("modelMatrix", &Matrix4::from_column_major(matrix).into());
+        }
+
+        ClippingPlaneCollection::new(&options.into())
+    }
+}
+
+/// Builder for a `ClippingPolygonCollection`
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // Cut a tunnel entrance out of the terrain
+/// let clipping = ClippingPolygons::new().polygon(vec![
+///     (-105.0077, 40.0438),
+///     (-105.0069, 40.0438),
+///     (-105.0069, 40.0432),
+///     (-105.0077, 40.0432),
+/// ]);
+///
+/// view! { <ViewerContainer globe_clipping=Clipping::from(clipping)>...</ViewerContainer> }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClippingPolygons {
+    /// Polygons as longitude, latitude pairs in degrees
+    polygons: Vec<Vec<(f64, f64)>>,
+    enabled: bool,
+    inverse: bool,
+}
+
+impl Default for ClippingPolygons {
+    fn default() -> Self {
+        Self {
+            polygons: Vec::new(),
+            enabled: true,
+            inverse: false,
+        }
+    }
+}
+
+impl ClippingPolygons {
+    /// Create an empty, enabled collection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a polygon given as longitude, latitude pairs in degrees
+    pub fn polygon(mut self, positions: Vec<(f64, f64)>) -> Self {
+        self.polygons.push(positions);
+        self
+    }
+
+    /// Set whether clipping is applied (default: true)
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Clip everything outside the polygons instead of inside them (default: false)
+    pub fn inverse(mut self, inverse: bool) -> Self {
+        self.inverse = inverse;
+        self
+    }
+
+    /// Build a new collection; each owner needs its own instance
+    #[cfg(target_arch = "wasm32")]
+    pub fn build(&self) -> ClippingPolygonCollection {
+        use crate::bindings::Cartesian3;
+        use js_sys::{Array, Object, Reflect};
+
+        let polygons: Array = self
+            .polygons
+            .iter()
+            .map(|positions| {
+                let degrees: Vec<f64> = positions
+                    .iter()
+                    .flat_map(|(longitude, latitude)| [*longitude, *latitude])
+                    .collect();
+                let options = Object::new();
+                let _ = Reflect::set(
+                    &options,
+                    &JsValue::from_str("positions"),
+                    &Cartesian3::from_degrees_array(&degrees),
+                );
+                JsValue::from(ClippingPolygon::new(&options.into()))
+            })
+            .collect();
+
+        let options = Object::new();
+        let _ = Reflect::set(&options, &JsValue::from_str("polygons"), &polygons);
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("enabled"),
+            &JsValue::from_bool(self.enabled),
+        );
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("inverse"),
+            &JsValue::from_bool(self.inverse),
+        );
+
+        ClippingPolygonCollection::new(&options.into())
+    }
+}
+
+/// Clipping for an owner that supports both planes and polygons (tilesets and the globe)
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let clipping = Clipping::new()
+///     .planes(ClippingPlanes::new().plane(ClippingPlaneOptions::clip_above(12.0)))
+///     .polygons(ClippingPolygons::new().polygon(footprint));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Clipping {
+    planes: Option<ClippingPlanes>,
+    polygons: Option<ClippingPolygons>,
+}
+
+impl Clipping {
+    /// Create clipping without planes or polygons
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the clipping planes
+    pub fn planes(mut self, planes: ClippingPlanes) -> Self {
+        self.planes = Some(planes);
+        self
+    }
+
+    /// Set the clipping polygons
+    pub fn polygons(mut self, polygons: ClippingPolygons) -> Self {
+        self.polygons = Some(polygons);
+        self
+    }
+}
+
+impl From<ClippingPlanes> for Clipping {
+    fn from(planes: ClippingPlanes) -> Self {
+        Clipping::new().planes(planes)
+    }
+}
+
+impl From<ClippingPolygons> for Clipping {
+    fn from(polygons: ClippingPolygons) -> Self {
+        Clipping::new().polygons(polygons)
+    }
+}
+
+/// Apply `clipping` to an owner's `clippingPlanes`/`clippingPolygons` properties, replacing
+/// (and thereby destroying) any previous collections
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn apply_clipping(owner: &JsValue, clipping: Option<&Clipping>) {
+    use js_sys::Reflect;
+
+    let planes = clipping
+        .and_then(|clipping| clipping.planes.as_ref())
+        .map_or(JsValue::UNDEFINED, |planes| planes.build().into());
+    let polygons = clipping
+        .and_then(|clipping| clipping.polygons.as_ref())
+        .map_or(JsValue::UNDEFINED, |polygons| polygons.build().into());
+    let _ = Reflect::set(owner, &JsValue::from_str("clippingPlanes"), &planes);
+    let _ = Reflect::set(owner, &JsValue::from_str("clippingPolygons"), &polygons);
+}
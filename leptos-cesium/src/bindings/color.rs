@@ -16,6 +16,18 @@ extern "C" {
 
     #[wasm_bindgen(method, js_name = withAlpha)]
     pub fn with_alpha(this: &Color, alpha: f64) -> Color;
+
+    #[wasm_bindgen(method, getter, js_name = red)]
+    fn red_component(this: &Color) -> f64;
+
+    #[wasm_bindgen(method, getter, js_name = green)]
+    fn green_component(this: &Color) -> f64;
+
+    #[wasm_bindgen(method, getter, js_name = blue)]
+    fn blue_component(this: &Color) -> f64;
+
+    #[wasm_bindgen(method, getter, js_name = alpha)]
+    fn alpha_component(this: &Color) -> f64;
 }
 
 // Helper to get static color properties
@@ -34,6 +46,16 @@ fn get_color_property(name: &str) -> Color {
 }
 
 impl Color {
+    /// Red, green, blue and alpha components, each from 0.0 to 1.0
+    pub fn to_rgba(&self) -> (f64, f64, f64, f64) {
+        (
+            self.red_component(),
+            self.green_component(),
+            self.blue_component(),
+            self.alpha_component(),
+        )
+    }
+
     #[cfg(target_arch = "wasm32")]
     pub fn white() -> Color {
         get_color_property("WHITE")
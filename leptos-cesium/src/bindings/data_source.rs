@@ -11,9 +11,91 @@ extern "C" {
     #[wasm_bindgen(method, js_name = add)]
     pub fn add(this: &DataSourceCollection, data_source: js_sys::Promise) -> js_sys::Promise;
 
+    /// Adds an already constructed data source (e.g. a `CustomDataSource`)
+    #[wasm_bindgen(method, js_name = add)]
+    pub fn add_data_source(
+        this: &DataSourceCollection,
+        data_source: &DataSource,
+    ) -> js_sys::Promise;
+
+    /// Removes a data source, optionally destroying it
+    #[wasm_bindgen(method, js_name = remove)]
+    pub fn remove(this: &DataSourceCollection, data_source: &DataSource, destroy: bool) -> bool;
+
+    #[wasm_bindgen(method, js_name = contains)]
+    pub fn contains(this: &DataSourceCollection, data_source: &DataSource) -> bool;
+
     #[wasm_bindgen(method, js_name = removeAll)]
     pub fn remove_all(this: &DataSourceCollection);
 
+    /// Number of data sources in the collection
+    #[wasm_bindgen(method, getter, js_name = length)]
+    pub fn length(this: &DataSourceCollection) -> u32;
+
+    #[wasm_bindgen(method, js_name = get)]
+    pub fn get(this: &DataSourceCollection, index: u32) -> DataSource;
+
+    #[wasm_bindgen(method, js_name = getByName)]
+    fn get_by_name_raw(this: &DataSourceCollection, name: &str) -> js_sys::Array;
+
+    /// Event fired when a data source is added to the collection
+    #[wasm_bindgen(method, getter, js_name = dataSourceAdded)]
+    pub fn data_source_added(this: &DataSourceCollection) -> crate::bindings::Event;
+
+    /// Event fired when a data source is removed from the collection
+    #[wasm_bindgen(method, getter, js_name = dataSourceRemoved)]
+    pub fn data_source_removed(this: &DataSourceCollection) -> crate::bindings::Event;
+
+    /// Common interface shared by every Cesium data source
+    ///
+    /// `DataSource` is an interface in Cesium, so concrete data sources do not inherit from it.
+    /// Always convert with `unchecked_into` rather than `dyn_into`.
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = DataSource)]
+    pub type DataSource;
+
+    #[wasm_bindgen(method, getter, js_name = name)]
+    pub fn name(this: &DataSource) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = show)]
+    pub fn show(this: &DataSource) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &DataSource, show: bool);
+
+    #[wasm_bindgen(method, getter, js_name = entities)]
+    pub fn entities(this: &DataSource) -> crate::bindings::EntityCollection;
+
+    /// Clustering options for this data source
+    #[wasm_bindgen(method, getter, js_name = clustering)]
+    pub fn clustering(this: &DataSource) -> crate::bindings::EntityCluster;
+
+    #[wasm_bindgen(method, setter, js_name = clustering)]
+    pub fn set_clustering(this: &DataSource, clustering: &crate::bindings::EntityCluster);
+
+    /// Data source for entities that are managed manually
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = DataSource, js_namespace = Cesium, js_name = CustomDataSource)]
+    pub type CustomDataSource;
+
+    #[wasm_bindgen(constructor, js_namespace = Cesium, js_class = CustomDataSource)]
+    pub fn new(name: Option<&str>) -> CustomDataSource;
+
+    #[wasm_bindgen(method, getter, js_name = name)]
+    pub fn name(this: &CustomDataSource) -> String;
+
+    #[wasm_bindgen(method, setter, js_name = name)]
+    pub fn set_name(this: &CustomDataSource, name: &str);
+
+    #[wasm_bindgen(method, getter, js_name = show)]
+    pub fn show(this: &CustomDataSource) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &CustomDataSource, show: bool);
+
+    #[wasm_bindgen(method, getter, js_name = entities)]
+    pub fn entities(this: &CustomDataSource) -> crate::bindings::EntityCollection;
+
     /// CZML data source
     #[wasm_bindgen(js_namespace = Cesium, js_name = CzmlDataSource)]
     pub type CzmlDataSource;
@@ -26,6 +108,16 @@ extern "C" {
     pub type DataSourceClock;
 }
 
+impl DataSourceCollection {
+    /// Data sources with the given name, in collection order
+    pub fn get_by_name(&self, name: &str) -> Vec<DataSource> {
+        self.get_by_name_raw(name)
+            .iter()
+            .map(|data_source| data_source.unchecked_into::<DataSource>())
+            .collect()
+    }
+}
+
 // Helper to call CzmlDataSource.load() using reflection
 #[cfg(target_arch = "wasm32")]
 pub fn czml_data_source_load(url: &str) -> js_sys::Promise {
@@ -5,6 +5,7 @@ use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
 extern "C" {
+    #[derive(Clone)]
     #[wasm_bindgen(js_namespace = Cesium, js_name = Entity)]
     pub type Entity;
 
@@ -35,6 +36,10 @@ extern "C" {
     #[wasm_bindgen(method, getter, js_name = show)]
     pub fn show(this: &Entity) -> bool;
 
+    /// Whether the entity and all its parents are shown
+    #[wasm_bindgen(method, getter, js_name = isShowing)]
+    pub fn is_showing(this: &Entity) -> bool;
+
     #[wasm_bindgen(js_namespace = Cesium, js_name = EntityCollection)]
     pub type EntityCollection;
 
@@ -46,4 +51,16 @@ extern "C" {
 
     #[wasm_bindgen(method, js_name = removeAll)]
     pub fn remove_all(this: &EntityCollection);
+
+    /// All entities in the collection
+    #[wasm_bindgen(method, getter, js_name = values)]
+    pub fn values(this: &EntityCollection) -> js_sys::Array;
+
+    /// Entity with the given id, if it is in the collection
+    #[wasm_bindgen(method, js_name = getById)]
+    pub fn get_by_id(this: &EntityCollection, id: &str) -> Option<Entity>;
+
+    /// Event fired when entities are added to or removed from the collection
+    #[wasm_bindgen(method, getter, js_name = collectionChanged)]
+    pub fn collection_changed(this: &EntityCollection) -> crate::bindings::Event;
 }
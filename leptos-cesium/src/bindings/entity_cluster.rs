@@ -0,0 +1,214 @@
+//! Cesium EntityCluster bindings and cluster options builder
+
+use crate::bindings::{Billboard, Event, Label, PointPrimitive};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Screen-space clustering of a data source's points, billboards and labels
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = EntityCluster)]
+    pub type EntityCluster;
+
+    #[wasm_bindgen(constructor, js_namespace = Cesium, js_class = EntityCluster)]
+    pub fn new(options: &JsValue) -> EntityCluster;
+
+    #[wasm_bindgen(method, getter, js_name = enabled)]
+    pub fn enabled(this: &EntityCluster) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enabled)]
+    pub fn set_enabled(this: &EntityCluster, enabled: bool);
+
+    /// Pixel range to extend the screen space bounding box
+    #[wasm_bindgen(method, getter, js_name = pixelRange)]
+    pub fn pixel_range(this: &EntityCluster) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = pixelRange)]
+    pub fn set_pixel_range(this: &EntityCluster, pixel_range: f64);
+
+    /// Minimum number of screen space objects that can be clustered
+    #[wasm_bindgen(method, getter, js_name = minimumClusterSize)]
+    pub fn minimum_cluster_size(this: &EntityCluster) -> u32;
+
+    #[wasm_bindgen(method, setter, js_name = minimumClusterSize)]
+    pub fn set_minimum_cluster_size(this: &EntityCluster, size: u32);
+
+    #[wasm_bindgen(method, getter, js_name = clusterBillboards)]
+    pub fn cluster_billboards(this: &EntityCluster) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = clusterBillboards)]
+    pub fn set_cluster_billboards(this: &EntityCluster, value: bool);
+
+    #[wasm_bindgen(method, getter, js_name = clusterLabels)]
+    pub fn cluster_labels(this: &EntityCluster) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = clusterLabels)]
+    pub fn set_cluster_labels(this: &EntityCluster, value: bool);
+
+    #[wasm_bindgen(method, getter, js_name = clusterPoints)]
+    pub fn cluster_points(this: &EntityCluster) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = clusterPoints)]
+    pub fn set_cluster_points(this: &EntityCluster, value: bool);
+
+    /// Event raised with `(clusteredEntities, cluster)` when a new cluster is displayed
+    #[wasm_bindgen(method, getter, js_name = clusterEvent)]
+    pub fn cluster_event(this: &EntityCluster) -> Event;
+
+    /// The primitives used to draw a single cluster, passed to the cluster event
+    #[derive(Clone)]
+    pub type Cluster;
+
+    #[wasm_bindgen(method, getter, js_name = billboard)]
+    pub fn billboard(this: &Cluster) -> Billboard;
+
+    #[wasm_bindgen(method, getter, js_name = label)]
+    pub fn label(this: &Cluster) -> Label;
+
+    #[wasm_bindgen(method, getter, js_name = point)]
+    pub fn point(this: &Cluster) -> PointPrimitive;
+}
+
+/// Builder for entity clustering settings on a data source
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let clustering = ClusterOptions::new()
+///     .pixel_range(40.0)
+///     .minimum_cluster_size(3)
+///     .cluster_labels(false)
+///     .expand_on_click(true);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterOptions {
+    enabled: bool,
+    pixel_range: f64,
+    minimum_cluster_size: u32,
+    cluster_points: bool,
+    cluster_billboards: bool,
+    cluster_labels: bool,
+    expand_on_click: bool,
+}
+
+/// Matches Cesium's `EntityCluster` defaults, so every setting can be applied and switching
+/// back to the default resets it
+impl Default for ClusterOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            pixel_range: 80.0,
+            minimum_cluster_size: 2,
+            cluster_points: true,
+            cluster_billboards: true,
+            cluster_labels: true,
+            expand_on_click: false,
+        }
+    }
+}
+
+impl ClusterOptions {
+    /// Create clustering options with clustering enabled and Cesium defaults otherwise
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable or disable clustering (default: true)
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Set the pixel range used to merge objects into a cluster (default: 80)
+    pub fn pixel_range(mut self, pixel_range: f64) -> Self {
+        self.pixel_range = pixel_range;
+        self
+    }
+
+    /// Set the minimum number of objects that form a cluster (default: 2)
+    pub fn minimum_cluster_size(mut self, size: u32) -> Self {
+        self.minimum_cluster_size = size;
+        self
+    }
+
+    /// Set whether entity points are clustered (default: true)
+    pub fn cluster_points(mut self, value: bool) -> Self {
+        self.cluster_points = value;
+        self
+    }
+
+    /// Set whether entity billboards are clustered (default: true)
+    pub fn cluster_billboards(mut self, value: bool) -> Self {
+        self.cluster_billboards = value;
+        self
+    }
+
+    /// Set whether entity labels are clustered (default: true)
+    pub fn cluster_labels(mut self, value: bool) -> Self {
+        self.cluster_labels = value;
+        self
+    }
+
+    /// Fly the camera to the clustered entities when a cluster is clicked (default: false)
+    pub fn expand_on_click(mut self, value: bool) -> Self {
+        self.expand_on_click = value;
+        self
+    }
+
+    /// Whether clicking a cluster should fly to its entities
+    pub fn expands_on_click(&self) -> bool {
+        self.enabled && self.expand_on_click
+    }
+
+    /// Apply these options to an existing EntityCluster
+    #[cfg(target_arch = "wasm32")]
+    pub fn apply(&self, cluster: &EntityCluster) {
+        cluster.set_pixel_range(self.pixel_range);
+        cluster.set_minimum_cluster_size(self.minimum_cluster_size);
+        cluster.set_cluster_points(self.cluster_points);
+        cluster.set_cluster_billboards(self.cluster_billboards);
+        cluster.set_cluster_labels(self.cluster_labels);
+        cluster.set_enabled(self.enabled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_match_cesium() {
+        let options = ClusterOptions::new();
+        assert!(options.enabled);
+        assert_eq!(options.pixel_range, 80.0);
+        assert_eq!(options.minimum_cluster_size, 2);
+        assert!(options.cluster_points && options.cluster_billboards && options.cluster_labels);
+        assert!(!options.expands_on_click());
+    }
+
+    #[test]
+    fn builder_overrides_and_reverts() {
+        let options = ClusterOptions::new()
+            .pixel_range(40.0)
+            .minimum_cluster_size(5)
+            .cluster_labels(false);
+        assert_eq!(options.pixel_range, 40.0);
+        assert_eq!(options.minimum_cluster_size, 5);
+        assert!(!options.cluster_labels);
+        assert_ne!(options, ClusterOptions::new());
+        assert_eq!(
+            options
+                .pixel_range(80.0)
+                .minimum_cluster_size(2)
+                .cluster_labels(true),
+            ClusterOptions::new()
+        );
+    }
+
+    #[test]
+    fn expands_only_while_enabled() {
+        let options = ClusterOptions::new().expand_on_click(true);
+        assert!(options.expands_on_click());
+        assert!(!options.enabled(false).expands_on_click());
+    }
+}
@@ -0,0 +1,112 @@
+//! Cesium Globe bindings
+
+use wasm_bindgen::prelude::*;
+
+use crate::bindings::{Cartographic, Color};
+
+#[wasm_bindgen]
+extern "C" {
+    /// The globe rendered in the scene, including its terrain and imagery
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Globe)]
+    pub type Globe;
+
+    /// The reference ellipsoid of a globe, e.g. WGS84
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Ellipsoid)]
+    pub type Ellipsoid;
+
+    /// The ellipsoid the globe is drawn on
+    #[wasm_bindgen(method, getter, js_name = ellipsoid)]
+    pub fn ellipsoid(this: &Globe) -> Ellipsoid;
+
+    /// Returns the height of the loaded terrain at the position, or `None` when no terrain tile
+    /// covering it is loaded
+    #[wasm_bindgen(method, js_name = getHeight)]
+    pub fn get_height(this: &Globe, cartographic: &Cartographic) -> Option<f64>;
+
+    /// Whether the globe is lit by the sun
+    #[wasm_bindgen(method, getter, js_name = enableLighting)]
+    pub fn enable_lighting(this: &Globe) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enableLighting)]
+    pub fn set_enable_lighting(this: &Globe, value: bool);
+
+    /// Whether primitives are hidden behind terrain
+    #[wasm_bindgen(method, getter, js_name = depthTestAgainstTerrain)]
+    pub fn depth_test_against_terrain(this: &Globe) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = depthTestAgainstTerrain)]
+    pub fn set_depth_test_against_terrain(this: &Globe, value: bool);
+
+    /// Color of the globe where no imagery is loaded
+    #[wasm_bindgen(method, getter, js_name = baseColor)]
+    pub fn base_color(this: &Globe) -> Color;
+
+    #[wasm_bindgen(method, setter, js_name = baseColor)]
+    pub fn set_base_color(this: &Globe, color: &Color);
+
+    /// Whether the atmosphere is drawn on the globe surface
+    #[wasm_bindgen(method, getter, js_name = showGroundAtmosphere)]
+    pub fn show_ground_atmosphere(this: &Globe) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = showGroundAtmosphere)]
+    pub fn set_show_ground_atmosphere(this: &Globe, value: bool);
+
+    /// Translucency settings of the globe surface
+    #[wasm_bindgen(method, getter, js_name = translucency)]
+    pub fn translucency(this: &Globe) -> GlobeTranslucency;
+
+    /// Translucency settings of the globe surface
+    #[wasm_bindgen(js_namespace = Cesium, js_name = GlobeTranslucency)]
+    pub type GlobeTranslucency;
+
+    #[wasm_bindgen(method, getter, js_name = enabled)]
+    pub fn enabled(this: &GlobeTranslucency) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = enabled)]
+    pub fn set_enabled(this: &GlobeTranslucency, value: bool);
+
+    /// Alpha of the front faces by camera distance, a `Cesium.NearFarScalar` or `undefined`
+    #[wasm_bindgen(method, setter, js_name = frontFaceAlphaByDistance)]
+    pub fn set_front_face_alpha_by_distance(this: &GlobeTranslucency, value: &JsValue);
+}
+
+/// A value interpolated by camera distance, mirrors `Cesium.NearFarScalar`
+///
+/// Closer than `near` the value is `near_value`, farther than `far` it is `far_value`, and in
+/// between it is interpolated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NearFarScalar {
+    /// Near distance in meters
+    pub near: f64,
+    /// Value at or closer than the near distance
+    pub near_value: f64,
+    /// Far distance in meters
+    pub far: f64,
+    /// Value at or beyond the far distance
+    pub far_value: f64,
+}
+
+impl NearFarScalar {
+    /// Create a scalar from its near and far distances and values
+    pub fn new(near: f64, near_value: f64, far: f64, far_value: f64) -> Self {
+        Self {
+            near,
+            near_value,
+            far,
+            far_value,
+        }
+    }
+
+    /// Create the Cesium `NearFarScalar`
+    #[cfg(target_arch = "wasm32")]
+    pub fn to_js(&self) -> JsValue {
+        let args = js_sys::Array::of4(
+            &JsValue::from_f64(self.near),
+            &JsValue::from_f64(self.near_value),
+            &JsValue::from_f64(self.far),
+            &JsValue::from_f64(self.far_value),
+        );
+        crate::bindings::imagery::construct_cesium("NearFarScalar", &args)
+            .unwrap_or(JsValue::UNDEFINED)
+    }
+}
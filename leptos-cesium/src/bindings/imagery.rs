@@ -0,0 +1,573 @@
+//! Cesium imagery layer bindings and imagery provider descriptions
+
+use wasm_bindgen::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::Rectangle;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Ordered collection of imagery layers rendered on the globe
+    #[wasm_bindgen(js_namespace = Cesium, js_name = ImageryLayerCollection)]
+    pub type ImageryLayerCollection;
+
+    #[wasm_bindgen(method, js_name = add)]
+    pub fn add(this: &ImageryLayerCollection, layer: &ImageryLayer);
+
+    #[wasm_bindgen(method, js_name = add)]
+    pub fn add_at(this: &ImageryLayerCollection, layer: &ImageryLayer, index: usize);
+
+    #[wasm_bindgen(method, js_name = remove)]
+    pub fn remove(this: &ImageryLayerCollection, layer: &ImageryLayer, destroy: bool) -> bool;
+
+    #[wasm_bindgen(method, js_name = removeAll)]
+    pub fn remove_all(this: &ImageryLayerCollection, destroy: bool);
+
+    #[wasm_bindgen(method, js_name = contains)]
+    pub fn contains(this: &ImageryLayerCollection, layer: &ImageryLayer) -> bool;
+
+    /// Returns the index of the layer, or -1 if it is not in the collection
+    #[wasm_bindgen(method, js_name = indexOf)]
+    pub fn index_of(this: &ImageryLayerCollection, layer: &ImageryLayer) -> i32;
+
+    #[wasm_bindgen(method, getter, js_name = length)]
+    pub fn length(this: &ImageryLayerCollection) -> usize;
+
+    /// An imagery layer displaying tiles from a single imagery provider
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = ImageryLayer)]
+    pub type ImageryLayer;
+
+    /// Creates a layer immediately while the provider promise resolves in the background
+    #[wasm_bindgen(static_method_of = ImageryLayer, js_name = fromProviderAsync)]
+    pub fn from_provider_async(provider: &js_sys::Promise, options: &JsValue) -> ImageryLayer;
+
+    #[wasm_bindgen(method, getter, js_name = alpha)]
+    pub fn alpha(this: &ImageryLayer) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = alpha)]
+    pub fn set_alpha(this: &ImageryLayer, value: f64);
+
+    #[wasm_bindgen(method, getter, js_name = brightness)]
+    pub fn brightness(this: &ImageryLayer) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = brightness)]
+    pub fn set_brightness(this: &ImageryLayer, value: f64);
+
+    #[wasm_bindgen(method, getter, js_name = contrast)]
+    pub fn contrast(this: &ImageryLayer) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = contrast)]
+    pub fn set_contrast(this: &ImageryLayer, value: f64);
+
+    #[wasm_bindgen(method, getter, js_name = hue)]
+    pub fn hue(this: &ImageryLayer) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = hue)]
+    pub fn set_hue(this: &ImageryLayer, value: f64);
+
+    #[wasm_bindgen(method, getter, js_name = saturation)]
+    pub fn saturation(this: &ImageryLayer) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = saturation)]
+    pub fn set_saturation(this: &ImageryLayer, value: f64);
+
+    #[wasm_bindgen(method, getter, js_name = gamma)]
+    pub fn gamma(this: &ImageryLayer) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = gamma)]
+    pub fn set_gamma(this: &ImageryLayer, value: f64);
+
+    #[wasm_bindgen(method, getter, js_name = show)]
+    pub fn show(this: &ImageryLayer) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &ImageryLayer, value: bool);
+
+    /// Whether the imagery provider has been created
+    #[wasm_bindgen(method, getter, js_name = ready)]
+    pub fn ready(this: &ImageryLayer) -> bool;
+
+    /// The imagery provider, `undefined` until the layer is ready
+    #[wasm_bindgen(method, getter, js_name = imageryProvider)]
+    pub fn imagery_provider(this: &ImageryLayer) -> JsValue;
+
+    #[wasm_bindgen(method, setter, js_name = splitDirection)]
+    fn set_split_direction_raw(this: &ImageryLayer, value: i32);
+
+    /// Event raised with the provider once it has been created
+    #[wasm_bindgen(method, getter, js_name = readyEvent)]
+    pub fn ready_event(this: &ImageryLayer) -> crate::bindings::Event;
+
+    /// Event raised with the error if the provider could not be created
+    #[wasm_bindgen(method, getter, js_name = errorEvent)]
+    pub fn error_event(this: &ImageryLayer) -> crate::bindings::Event;
+}
+
+impl ImageryLayer {
+    /// Sets which side of the splitter the layer is shown on
+    pub fn set_split_direction(&self, direction: SplitDirection) {
+        self.set_split_direction_raw(direction as i32);
+    }
+}
+
+/// Mirrors `Cesium.SplitDirection`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SplitDirection {
+    Left = -1,
+    #[default]
+    None = 0,
+    Right = 1,
+}
+
+/// Mirrors `Cesium.BingMapsStyle`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BingMapsStyle {
+    Aerial,
+    #[default]
+    AerialWithLabelsOnDemand,
+    RoadOnDemand,
+    CanvasDark,
+    CanvasLight,
+    CanvasGray,
+}
+
+impl BingMapsStyle {
+    /// The string value Cesium expects for `mapStyle`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BingMapsStyle::Aerial => "Aerial",
+            BingMapsStyle::AerialWithLabelsOnDemand => "AerialWithLabelsOnDemand",
+            BingMapsStyle::RoadOnDemand => "RoadOnDemand",
+            BingMapsStyle::CanvasDark => "CanvasDark",
+            BingMapsStyle::CanvasLight => "CanvasLight",
+            BingMapsStyle::CanvasGray => "CanvasGray",
+        }
+    }
+}
+
+/// Tiling scheme used to address the tiles of a tile server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TilingScheme {
+    /// Web Mercator (EPSG:3857) tiles, one root tile (the XYZ/slippy map layout)
+    #[default]
+    WebMercator,
+    /// Geographic (EPSG:4326) tiles, two root tiles side by side
+    Geographic,
+}
+
+impl TilingScheme {
+    /// The name of the Cesium tiling scheme class
+    pub fn class_name(&self) -> &'static str {
+        match self {
+            TilingScheme::WebMercator => "WebMercatorTilingScheme",
+            TilingScheme::Geographic => "GeographicTilingScheme",
+        }
+    }
+}
+
+/// Tiling options shared by the tile server providers
+#[derive(Debug, Clone, PartialEq, Default)]
+struct TileSourceOptions {
+    tiling_scheme: Option<TilingScheme>,
+    minimum_level: Option<u32>,
+    maximum_level: Option<u32>,
+    /// West, south, east, north in degrees
+    rectangle: Option<(f64, f64, f64, f64)>,
+    credit: Option<String>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl TileSourceOptions {
+    fn apply(&self, options: &js_sys::Object) {
+        use js_sys::{Array, Reflect};
+
+        let set = |key: &str, value: &JsValue| {
+            let _ = Reflect::set(options, &JsValue::from_str(key), value);
+        };
+
+        if let Some(scheme) = self.tiling_scheme
+            && let Ok(scheme) = construct_cesium(scheme.class_name(), &Array::new())
+        {
+            set("tilingScheme", &scheme);
+        }
+        if let Some(level) = self.minimum_level {
+            set("minimumLevel", &JsValue::from(level));
+        }
+        if let Some(level) = self.maximum_level {
+            set("maximumLevel", &JsValue::from(level));
+        }
+        if let Some((west, south, east, north)) = self.rectangle {
+            set(
+                "rectangle",
+                &Rectangle::from_degrees(west, south, east, north).into(),
+            );
+        }
+        if let Some(credit) = &self.credit {
+            set("credit", &JsValue::from_str(credit));
+        }
+    }
+}
+
+/// Options for `UrlTemplateImageryProvider`
+///
+/// The template may contain these placeholders:
+/// * `{z}`, `{x}`, `{y}` - tile level and coordinates, with y = 0 at the top (XYZ layout)
+/// * `{reverseY}` - y counted from the bottom, as written by TMS tools such as gdal2tiles
+/// * `{s}` - one of the [`subdomains`](Self::subdomains), rotated between requests
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let options = UrlTemplateOptions::new("http://tiles.local/{z}/{x}/{reverseY}.png")
+///     .tiling_scheme(TilingScheme::Geographic)
+///     .maximum_level(8)
+///     .rectangle(-10.0, 35.0, 30.0, 60.0)
+///     .credit("© Local survey office");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct UrlTemplateOptions {
+    url: String,
+    subdomains: Vec<String>,
+    tiles: TileSourceOptions,
+}
+
+impl UrlTemplateOptions {
+    /// Create options for the given URL template
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            subdomains: Vec::new(),
+            tiles: TileSourceOptions::default(),
+        }
+    }
+
+    /// Set the subdomains substituted for `{s}` (default: "a", "b", "c")
+    pub fn subdomains<S: Into<String>>(mut self, subdomains: impl IntoIterator<Item = S>) -> Self {
+        self.subdomains = subdomains.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the tiling scheme of the tile server (default: Web Mercator)
+    pub fn tiling_scheme(mut self, scheme: TilingScheme) -> Self {
+        self.tiles.tiling_scheme = Some(scheme);
+        self
+    }
+
+    /// Set the lowest level of detail the server provides (default: 0)
+    pub fn minimum_level(mut self, level: u32) -> Self {
+        self.tiles.minimum_level = Some(level);
+        self
+    }
+
+    /// Set the highest level of detail the server provides; deeper tiles are not requested
+    pub fn maximum_level(mut self, level: u32) -> Self {
+        self.tiles.maximum_level = Some(level);
+        self
+    }
+
+    /// Limit the covered area to west, south, east, north in degrees
+    pub fn rectangle(mut self, west: f64, south: f64, east: f64, north: f64) -> Self {
+        self.tiles.rectangle = Some((west, south, east, north));
+        self
+    }
+
+    /// Set the credit/attribution shown for the imagery
+    pub fn credit(mut self, credit: impl Into<String>) -> Self {
+        self.tiles.credit = Some(credit.into());
+        self
+    }
+
+    /// Build the options object for the UrlTemplateImageryProvider constructor
+    #[cfg(target_arch = "wasm32")]
+    pub fn build(&self) -> JsValue {
+        use js_sys::{Array, Object, Reflect};
+
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("url"),
+            &JsValue::from_str(&self.url),
+        );
+        if !self.subdomains.is_empty() {
+            let subdomains: Array = self
+                .subdomains
+                .iter()
+                .map(|subdomain| JsValue::from_str(subdomain))
+                .collect();
+            let _ = Reflect::set(&options, &JsValue::from_str("subdomains"), &subdomains);
+        }
+        self.tiles.apply(&options);
+
+        options.into()
+    }
+}
+
+/// Options for `TileMapServiceImageryProvider`
+///
+/// The URL points to the directory containing `tilemapresource.xml`. When that file is missing
+/// the provider falls back to the configured tiling options.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileMapServiceOptions {
+    url: String,
+    file_extension: Option<String>,
+    tiles: TileSourceOptions,
+}
+
+impl TileMapServiceOptions {
+    /// Create options for the tile set at the given URL
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            file_extension: None,
+            tiles: TileSourceOptions::default(),
+        }
+    }
+
+    /// Set the file extension of the tile images (default: "png")
+    pub fn file_extension(mut self, extension: impl Into<String>) -> Self {
+        self.file_extension = Some(extension.into());
+        self
+    }
+
+    /// Set the tiling scheme of the tile set (default: Web Mercator)
+    pub fn tiling_scheme(mut self, scheme: TilingScheme) -> Self {
+        self.tiles.tiling_scheme = Some(scheme);
+        self
+    }
+
+    /// Set the lowest level of detail the tile set provides (default: 0)
+    pub fn minimum_level(mut self, level: u32) -> Self {
+        self.tiles.minimum_level = Some(level);
+        self
+    }
+
+    /// Set the highest level of detail the tile set provides
+    pub fn maximum_level(mut self, level: u32) -> Self {
+        self.tiles.maximum_level = Some(level);
+        self
+    }
+
+    /// Limit the covered area to west, south, east, north in degrees
+    pub fn rectangle(mut self, west: f64, south: f64, east: f64, north: f64) -> Self {
+        self.tiles.rectangle = Some((west, south, east, north));
+        self
+    }
+
+    /// Set the credit/attribution shown for the imagery
+    pub fn credit(mut self, credit: impl Into<String>) -> Self {
+        self.tiles.credit = Some(credit.into());
+        self
+    }
+
+    /// Build the options object for TileMapServiceImageryProvider.fromUrl
+    #[cfg(target_arch = "wasm32")]
+    pub fn build(&self) -> JsValue {
+        use js_sys::{Object, Reflect};
+
+        let options = Object::new();
+        if let Some(extension) = &self.file_extension {
+            let _ = Reflect::set(
+                &options,
+                &JsValue::from_str("fileExtension"),
+                &JsValue::from_str(extension),
+            );
+        }
+        self.tiles.apply(&options);
+
+        options.into()
+    }
+}
+
+/// The source of tiles for an `<ImageryLayer>`
+///
+/// Each variant maps to one Cesium imagery provider. Providers that Cesium creates
+/// asynchronously (`fromUrl`/`fromAssetId`) are handled transparently.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImageryProvider {
+    /// OpenStreetMap tiles, optionally from a custom tile server URL
+    OpenStreetMap { url: Option<String> },
+    /// Tiles addressed by a URL template
+    UrlTemplate(UrlTemplateOptions),
+    /// OGC Web Map Service
+    WebMapService {
+        url: String,
+        layers: String,
+        /// Additional query parameters for GetMap requests
+        parameters: Vec<(String, String)>,
+    },
+    /// OGC Web Map Tile Service
+    WebMapTileService {
+        url: String,
+        layer: String,
+        style: String,
+        tile_matrix_set_id: String,
+        format: Option<String>,
+    },
+    /// Tiles produced by a Tile Map Service (e.g. gdal2tiles, MapTiler)
+    TileMapService(TileMapServiceOptions),
+    /// ArcGIS MapServer REST endpoint
+    ArcGisMapServer { url: String },
+    /// Bing Maps imagery
+    Bing {
+        key: String,
+        map_style: BingMapsStyle,
+    },
+    /// Imagery asset hosted on Cesium Ion
+    IonAsset(u32),
+    /// A single image covering the whole globe
+    SingleTile { url: String },
+}
+
+#[cfg(target_arch = "wasm32")]
+impl ImageryProvider {
+    /// Start creating the provider, resolving to the Cesium imagery provider instance
+    pub fn create(&self) -> js_sys::Promise {
+        use js_sys::{Array, Object, Reflect};
+
+        let set = |options: &Object, key: &str, value: &JsValue| {
+            let _ = Reflect::set(options, &JsValue::from_str(key), value);
+        };
+
+        match self {
+            ImageryProvider::OpenStreetMap { url } => {
+                let options = Object::new();
+                if let Some(url) = url {
+                    set(&options, "url", &JsValue::from_str(url));
+                }
+                construct_provider("OpenStreetMapImageryProvider", &options.into())
+            }
+            ImageryProvider::UrlTemplate(options) => {
+                construct_provider("UrlTemplateImageryProvider", &options.build())
+            }
+            ImageryProvider::WebMapService {
+                url,
+                layers,
+                parameters,
+            } => {
+                let options = Object::new();
+                set(&options, "url", &JsValue::from_str(url));
+                set(&options, "layers", &JsValue::from_str(layers));
+                if !parameters.is_empty() {
+                    let params = Object::new();
+                    for (key, value) in parameters {
+                        set(&params, key, &JsValue::from_str(value));
+                    }
+                    set(&options, "parameters", &params);
+                }
+                construct_provider("WebMapServiceImageryProvider", &options.into())
+            }
+            ImageryProvider::WebMapTileService {
+                url,
+                layer,
+                style,
+                tile_matrix_set_id,
+                format,
+            } => {
+                let options = Object::new();
+                set(&options, "url", &JsValue::from_str(url));
+                set(&options, "layer", &JsValue::from_str(layer));
+                set(&options, "style", &JsValue::from_str(style));
+                set(
+                    &options,
+                    "tileMatrixSetID",
+                    &JsValue::from_str(tile_matrix_set_id),
+                );
+                if let Some(format) = format {
+                    set(&options, "format", &JsValue::from_str(format));
+                }
+                construct_provider("WebMapTileServiceImageryProvider", &options.into())
+            }
+            ImageryProvider::TileMapService(options) => call_provider_factory(
+                "TileMapServiceImageryProvider",
+                "fromUrl",
+                &Array::of2(&JsValue::from_str(&options.url), &options.build()),
+            ),
+            ImageryProvider::ArcGisMapServer { url } => call_provider_factory(
+                "ArcGisMapServerImageryProvider",
+                "fromUrl",
+                &Array::of1(&JsValue::from_str(url)),
+            ),
+            ImageryProvider::Bing { key, map_style } => {
+                let options = Object::new();
+                set(&options, "key", &JsValue::from_str(key));
+                set(&options, "mapStyle", &JsValue::from_str(map_style.as_str()));
+                call_provider_factory(
+                    "BingMapsImageryProvider",
+                    "fromUrl",
+                    &Array::of2(&JsValue::from_str("https://dev.virtualearth.net"), &options),
+                )
+            }
+            ImageryProvider::IonAsset(asset_id) => call_provider_factory(
+                "IonImageryProvider",
+                "fromAssetId",
+                &Array::of1(&JsValue::from_f64(*asset_id as f64)),
+            ),
+            ImageryProvider::SingleTile { url } => call_provider_factory(
+                "SingleTileImageryProvider",
+                "fromUrl",
+                &Array::of1(&JsValue::from_str(url)),
+            ),
+        }
+    }
+}
+
+/// Internal helper to look up a class on the Cesium global
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn cesium_class(class_name: &str) -> JsValue {
+    use js_sys::{Reflect, global};
+
+    let cesium = Reflect::get(&global(), &JsValue::from_str("Cesium"))
+        .expect("Cesium global to be available");
+    Reflect::get(&cesium, &JsValue::from_str(class_name))
+        .unwrap_or_else(|_| panic!("Cesium.{} to exist", class_name))
+}
+
+/// Internal helper to call `new Cesium[class_name](...args)`, returning what the constructor
+/// throws on invalid arguments
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn construct_cesium(class_name: &str, args: &js_sys::Array) -> Result<JsValue, JsValue> {
+    use js_sys::{Function, Reflect};
+    use wasm_bindgen::JsCast;
+
+    let class: Function = cesium_class(class_name)
+        .dyn_into()
+        .unwrap_or_else(|_| panic!("Cesium.{} to be a constructor", class_name));
+    Reflect::construct(&class, args)
+}
+
+/// Internal helper to call `new Cesium[class_name](options)`
+///
+/// Errors surface through the rejected promise, like those of `call_provider_factory`.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn construct_provider(class_name: &str, options: &JsValue) -> js_sys::Promise {
+    use js_sys::Promise;
+
+    match construct_cesium(class_name, &js_sys::Array::of1(options)) {
+        Ok(provider) => Promise::resolve(&provider),
+        Err(err) => Promise::reject(&err),
+    }
+}
+
+/// Internal helper to call an async factory such as `Cesium[class_name].fromUrl(...)`
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn call_provider_factory(
+    class_name: &str,
+    factory: &str,
+    args: &js_sys::Array,
+) -> js_sys::Promise {
+    use js_sys::{Function, Promise, Reflect};
+    use wasm_bindgen::JsCast;
+
+    let class = cesium_class(class_name);
+    let factory_fn: Function = Reflect::get(&class, &JsValue::from_str(factory))
+        .ok()
+        .and_then(|f| f.dyn_into().ok())
+        .unwrap_or_else(|| panic!("Cesium.{}.{} to be callable", class_name, factory));
+
+    // Errors surface through the rejected promise so the layer can report them
+    match Reflect::apply(&factory_fn, &class, args) {
+        Ok(result) => Promise::resolve(&result),
+        Err(err) => Promise::reject(&err),
+    }
+}
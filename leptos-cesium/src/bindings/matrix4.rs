@@ -0,0 +1,80 @@
+//! Cesium Matrix4 utilities for model transforms
+
+use wasm_bindgen::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::Cartesian3;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A 4x4 matrix, used as the model matrix that places primitives in the world
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Matrix4)]
+    pub type Matrix4;
+}
+
+/// Internal helper using reflection to call a static function on a Cesium class
+#[cfg(target_arch = "wasm32")]
+fn call_static(class_name: &str, name: &str, args: &js_sys::Array) -> JsValue {
+    use js_sys::{Function, Reflect, global};
+
+    let cesium = Reflect::get(&global(), &JsValue::from_str("Cesium"))
+        .expect("Cesium global to be available");
+    let class = Reflect::get(&cesium, &JsValue::from_str(class_name))
+        .unwrap_or_else(|_| panic!("Cesium.{} to exist", class_name));
+    let function: Function = Reflect::get(&class, &JsValue::from_str(name))
+        .ok()
+        .and_then(|f| f.dyn_into().ok())
+        .unwrap_or_else(|| panic!("Cesium.{}.{} to be callable", class_name, name));
+    Reflect::apply(&function, &class, args)
+        .unwrap_or_else(|_| panic!("Cesium.{}.{} call to succeed", class_name, name))
+}
+
+impl Matrix4 {
+    /// The identity matrix, which leaves positions unchanged
+    #[cfg(target_arch = "wasm32")]
+    pub fn identity() -> Self {
+        call_static(
+            "Matrix4",
+            "fromUniformScale",
+            &js_sys::Array::of1(&JsValue::from_f64(1.0)),
+        )
+        .unchecked_into()
+    }
+
+    /// Create a matrix from 16 values in column-major order.
+    ///
+    /// Calls Cesium.Matrix4.fromArray internally.
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_column_major(values: &[f64; 16]) -> Self {
+        let array: js_sys::Array = values.iter().map(|v| JsValue::from_f64(*v)).collect();
+        call_static("Matrix4", "fromArray", &js_sys::Array::of1(&array)).unchecked_into()
+    }
+
+    /// Create a matrix that translates by the given offset in world coordinates.
+    ///
+    /// Calls Cesium.Matrix4.fromTranslation internally.
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_translation(translation: &Cartesian3) -> Self {
+        call_static(
+            "Matrix4",
+            "fromTranslation",
+            &js_sys::Array::of1(translation),
+        )
+        .unchecked_into()
+    }
+
+    /// Create a matrix whose local axes point east, north and up at `origin`, for placing a
+    /// model authored in local coordinates on the globe.
+    ///
+    /// Calls Cesium.Transforms.eastNorthUpToFixedFrame internally.
+    #[cfg(target_arch = "wasm32")]
+    pub fn east_north_up(origin: &Cartesian3) -> Self {
+        call_static(
+            "Transforms",
+            "eastNorthUpToFixedFrame",
+            &js_sys::Array::of1(origin),
+        )
+        .unchecked_into()
+    }
+}
@@ -1,40 +1,74 @@
 //! Cesium bindings entry point.
 
+pub mod atmosphere;
 pub mod camera;
+pub mod camera_controller;
+pub mod camera_view;
 pub mod cartesian2;
+pub mod cartographic;
+pub mod clipping;
 pub mod color;
 pub mod coordinates;
 pub mod data_source;
 pub mod entity;
+pub mod entity_cluster;
 pub mod generated;
 pub mod geojson;
 pub mod globals;
+pub mod globe;
+pub mod imagery;
 pub mod ion;
 pub mod materials;
 pub mod math;
+pub mod matrix4;
+pub mod picking;
 pub mod polygon_hierarchy;
+pub mod primitives;
 pub mod property;
 pub mod rectangle;
+pub mod scene_mode;
+pub mod screen_space_event_handler;
+pub mod screenshot;
+pub mod selection;
+pub mod terrain;
+pub mod tile_style;
 pub mod tileset;
 pub mod viewer;
 
+pub use atmosphere::*;
 pub use camera::*;
+pub use camera_controller::*;
+pub use camera_view::*;
 pub use cartesian2::*;
+pub use cartographic::*;
+pub use clipping::*;
 pub use color::*;
 pub use coordinates::*;
 pub use data_source::*;
 pub use entity::*;
+pub use entity_cluster::*;
 #[allow(unused_imports)]
 pub use generated::*;
 pub use geojson::*;
 #[cfg(target_arch = "wasm32")]
 pub use globals::*;
+pub use globe::*;
+pub use imagery::*;
 #[cfg(target_arch = "wasm32")]
 pub use ion::*;
 pub use materials::*;
 pub use math::*;
+pub use matrix4::*;
+pub use picking::*;
 pub use polygon_hierarchy::*;
+pub use primitives::*;
 pub use property::*;
 pub use rectangle::*;
+pub use scene_mode::*;
+pub use screen_space_event_handler::*;
+pub use screenshot::*;
+pub use selection::*;
+pub use terrain::*;
+pub use tile_style::{Expr, TileStyle};
 pub use tileset::*;
 pub use viewer::*;
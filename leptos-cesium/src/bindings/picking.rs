@@ -0,0 +1,250 @@
+//! Typed scene picking from screen coordinates
+
+use wasm_bindgen::prelude::*;
+
+use crate::bindings::{
+    Cartesian2, Cartesian3, Cartographic, Cesium3DTileFeature, Ellipsoid, Entity, Scene,
+};
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{SceneMode, Viewer};
+
+#[wasm_bindgen]
+extern "C" {
+    /// Transforms between world and window coordinates
+    #[wasm_bindgen(js_namespace = Cesium, js_name = SceneTransforms)]
+    pub type SceneTransforms;
+
+    /// Window position of a world position, or `None` when it is behind the camera
+    #[wasm_bindgen(static_method_of = SceneTransforms, js_name = worldToWindowCoordinates)]
+    pub fn world_to_window_coordinates(scene: &Scene, position: &Cartesian3) -> Option<Cartesian2>;
+
+    /// Tells whether points are hidden behind an ellipsoid as seen from a camera position
+    #[wasm_bindgen(js_namespace = Cesium, js_name = EllipsoidalOccluder)]
+    pub type EllipsoidalOccluder;
+
+    #[wasm_bindgen(constructor, js_namespace = Cesium, js_class = EllipsoidalOccluder)]
+    pub fn new(ellipsoid: &Ellipsoid, camera_position: &Cartesian3) -> EllipsoidalOccluder;
+
+    /// Whether the point is on the near side of the ellipsoid's horizon
+    #[wasm_bindgen(method, js_name = isPointVisible)]
+    pub fn is_point_visible(this: &EllipsoidalOccluder, occludee: &Cartesian3) -> bool;
+}
+
+/// What is under a screen position
+#[derive(Clone)]
+pub enum PickResult {
+    /// An entity of the viewer or of a data source
+    Entity(Entity),
+    /// A feature of a 3D Tiles tileset, e.g. a building
+    Tile3DFeature(Cesium3DTileFeature),
+    /// Any other primitive, such as a model, a primitive added directly to the scene, or a
+    /// cluster of entities
+    Primitive(JsValue),
+    /// A position on the globe where no object was hit
+    GlobePosition(Cartographic),
+}
+
+impl PickResult {
+    /// The picked entity, if an entity was picked
+    pub fn entity(&self) -> Option<&Entity> {
+        match self {
+            Self::Entity(entity) => Some(entity),
+            _ => None,
+        }
+    }
+
+    /// Classify an object returned by `scene.pick` or `scene.drillPick`
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_picked(picked: JsValue) -> Option<Self> {
+        if picked.is_undefined() || picked.is_null() {
+            return None;
+        }
+        let picked = match picked.dyn_into::<Cesium3DTileFeature>() {
+            Ok(feature) => return Some(Self::Tile3DFeature(feature)),
+            Err(picked) => picked,
+        };
+
+        let id = js_sys::Reflect::get(&picked, &JsValue::from_str("id")).ok();
+        if let Some(entity) = id.and_then(|id| id.dyn_into::<Entity>().ok()) {
+            return Some(Self::Entity(entity));
+        }
+        let primitive = js_sys::Reflect::get(&picked, &JsValue::from_str("primitive"))
+            .ok()
+            .filter(|primitive| !primitive.is_undefined())
+            .unwrap_or(picked);
+        Some(Self::Primitive(primitive))
+    }
+}
+
+/// The topmost object at a window position, or the globe position below it
+///
+/// Falls back to `pick_position` when no object is hit, so the result is `None` only when the
+/// position is off the globe.
+#[cfg(target_arch = "wasm32")]
+pub fn pick(viewer: &Viewer, window_position: &Cartesian2) -> Option<PickResult> {
+    PickResult::from_picked(viewer.scene().pick(window_position))
+        .or_else(|| pick_position(viewer, window_position).map(PickResult::GlobePosition))
+}
+
+/// Every object at a window position, topmost first
+///
+/// `limit` caps the number of objects, which keeps drilling through dense scenes cheap.
+#[cfg(target_arch = "wasm32")]
+pub fn drill_pick(
+    viewer: &Viewer,
+    window_position: &Cartesian2,
+    limit: Option<u32>,
+) -> Vec<PickResult> {
+    viewer
+        .scene()
+        .drill_pick(window_position, limit)
+        .iter()
+        .filter_map(PickResult::from_picked)
+        .collect()
+}
+
+/// The position on the rendered surface at a window position
+///
+/// Reads the depth buffer, so the position lands on terrain, 3D Tiles and models. Falls back to
+/// `pick_ellipsoid` where depth picking is unsupported or nothing was rendered at the position.
+#[cfg(target_arch = "wasm32")]
+pub fn pick_position(viewer: &Viewer, window_position: &Cartesian2) -> Option<Cartographic> {
+    let scene = viewer.scene();
+    let position = if scene.pick_position_supported() {
+        scene.pick_position(window_position).ok().flatten()
+    } else {
+        None
+    };
+    match position {
+        Some(position) => Cartographic::from_cartesian(&position),
+        None => pick_ellipsoid(viewer, window_position),
+    }
+}
+
+/// Where the ray through a window position hits the ellipsoid, ignoring terrain and objects
+#[cfg(target_arch = "wasm32")]
+pub fn pick_ellipsoid(viewer: &Viewer, window_position: &Cartesian2) -> Option<Cartographic> {
+    viewer
+        .camera()
+        .pick_ellipsoid(window_position)
+        .and_then(|position| Cartographic::from_cartesian(&position))
+}
+
+/// Shown entities whose position is inside the window rectangle spanned by two corners
+///
+/// Covers the viewer's entities and those of every data source. Entities without a position,
+/// such as most polygons, are never inside, and neither are entities behind the globe.
+#[cfg(target_arch = "wasm32")]
+pub fn pick_entities_in_rectangle(
+    viewer: &Viewer,
+    corner: &Cartesian2,
+    opposite_corner: &Cartesian2,
+) -> Vec<Entity> {
+    let (min_x, max_x) = min_max(corner.x(), opposite_corner.x());
+    let (min_y, max_y) = min_max(corner.y(), opposite_corner.y());
+    pick_entities_where(viewer, |x, y| {
+        (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y)
+    })
+}
+
+/// Shown entities whose position is inside the window polygon through `vertices`, e.g. a
+/// lasso drawn by the user
+///
+/// The polygon is closed between the last and first vertex. Like
+/// `pick_entities_in_rectangle`, entities without a position or behind the globe are skipped.
+#[cfg(target_arch = "wasm32")]
+pub fn pick_entities_in_polygon(viewer: &Viewer, vertices: &[Cartesian2]) -> Vec<Entity> {
+    let vertices: Vec<(f64, f64)> = vertices.iter().map(|v| (v.x(), v.y())).collect();
+    pick_entities_where(viewer, |x, y| polygon_contains(&vertices, x, y))
+}
+
+/// Shown entities whose window position satisfies `contains`
+#[cfg(target_arch = "wasm32")]
+fn pick_entities_where(viewer: &Viewer, contains: impl Fn(f64, f64) -> bool) -> Vec<Entity> {
+    let scene = viewer.scene();
+    let time = viewer.clock().current_time();
+    // Window coordinates are also computed for positions on the far side of the globe
+    let occluder = scene
+        .globe()
+        .filter(|_| scene.mode() == SceneMode::Scene3D.to_cesium())
+        .map(|globe| EllipsoidalOccluder::new(&globe.ellipsoid(), &viewer.camera().position_wc()));
+
+    let data_sources = viewer.data_sources();
+    let collections = std::iter::once(viewer.entities())
+        .chain((0..data_sources.length()).map(|index| data_sources.get(index).entities()));
+    collections
+        .flat_map(|entities| entities.values().iter().collect::<Vec<_>>())
+        .map(|entity| entity.unchecked_into::<Entity>())
+        .filter(|entity| {
+            entity.is_showing()
+                && entity
+                    .position()
+                    .and_then(|position| position.get_value(Some(&time)))
+                    .filter(|position| {
+                        occluder
+                            .as_ref()
+                            .is_none_or(|occluder| occluder.is_point_visible(position))
+                    })
+                    .and_then(|position| {
+                        SceneTransforms::world_to_window_coordinates(&scene, &position)
+                    })
+                    .is_some_and(|window| contains(window.x(), window.y()))
+        })
+        .collect()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn min_max(a: f64, b: f64) -> (f64, f64) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+/// Even-odd test of a point against a closed polygon
+#[cfg(any(target_arch = "wasm32", test))]
+fn polygon_contains(vertices: &[(f64, f64)], x: f64, y: f64) -> bool {
+    let Some(&last) = vertices.last() else {
+        return false;
+    };
+    let mut inside = false;
+    let mut previous = last;
+    for &(vertex_x, vertex_y) in vertices {
+        let (previous_x, previous_y) = previous;
+        if (vertex_y > y) != (previous_y > y)
+            && x < (previous_x - vertex_x) * (y - vertex_y) / (previous_y - vertex_y) + vertex_x
+        {
+            inside = !inside;
+        }
+        previous = (vertex_x, vertex_y);
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygon_contains() {
+        let square = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        assert!(polygon_contains(&square, 5.0, 5.0));
+        assert!(!polygon_contains(&square, 15.0, 5.0));
+        assert!(!polygon_contains(&[], 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_polygon_contains_concave() {
+        // A "U" open at the top
+        let u = [
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 10.0),
+            (7.0, 10.0),
+            (7.0, 3.0),
+            (3.0, 3.0),
+            (3.0, 10.0),
+            (0.0, 10.0),
+        ];
+        assert!(polygon_contains(&u, 1.0, 8.0));
+        assert!(!polygon_contains(&u, 5.0, 8.0));
+    }
+}
@@ -0,0 +1,111 @@
+//! Bindings for Cesium billboard, label and point primitives.
+//!
+//! These are the low-level primitives Cesium uses to draw clusters; entity graphics
+//! are configured through the graphics components instead.
+
+use crate::bindings::{Cartesian2, Color};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A viewport-aligned image positioned in the 3D scene
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Billboard)]
+    pub type Billboard;
+
+    #[wasm_bindgen(method, getter, js_name = show)]
+    pub fn show(this: &Billboard) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &Billboard, show: bool);
+
+    /// Sets the image from a URL or data URI
+    #[wasm_bindgen(method, setter, js_name = image)]
+    pub fn set_image(this: &Billboard, image: &str);
+
+    #[wasm_bindgen(method, setter, js_name = color)]
+    pub fn set_color(this: &Billboard, color: &Color);
+
+    #[wasm_bindgen(method, setter, js_name = scale)]
+    pub fn set_scale(this: &Billboard, scale: f64);
+
+    #[wasm_bindgen(method, setter, js_name = pixelOffset)]
+    pub fn set_pixel_offset(this: &Billboard, offset: &Cartesian2);
+
+    /// User-defined value returned when the billboard is picked
+    #[wasm_bindgen(method, getter, js_name = id)]
+    pub fn id(this: &Billboard) -> JsValue;
+
+    #[wasm_bindgen(method, setter, js_name = id)]
+    pub fn set_id(this: &Billboard, id: &JsValue);
+
+    /// A text label positioned in the 3D scene
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Label)]
+    pub type Label;
+
+    #[wasm_bindgen(method, getter, js_name = show)]
+    pub fn show(this: &Label) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &Label, show: bool);
+
+    #[wasm_bindgen(method, getter, js_name = text)]
+    pub fn text(this: &Label) -> String;
+
+    #[wasm_bindgen(method, setter, js_name = text)]
+    pub fn set_text(this: &Label, text: &str);
+
+    /// Sets the CSS font, e.g. `"16px sans-serif"`
+    #[wasm_bindgen(method, setter, js_name = font)]
+    pub fn set_font(this: &Label, font: &str);
+
+    #[wasm_bindgen(method, setter, js_name = fillColor)]
+    pub fn set_fill_color(this: &Label, color: &Color);
+
+    #[wasm_bindgen(method, setter, js_name = outlineColor)]
+    pub fn set_outline_color(this: &Label, color: &Color);
+
+    #[wasm_bindgen(method, setter, js_name = outlineWidth)]
+    pub fn set_outline_width(this: &Label, width: f64);
+
+    #[wasm_bindgen(method, setter, js_name = pixelOffset)]
+    pub fn set_pixel_offset(this: &Label, offset: &Cartesian2);
+
+    /// User-defined value returned when the label is picked
+    #[wasm_bindgen(method, getter, js_name = id)]
+    pub fn id(this: &Label) -> JsValue;
+
+    #[wasm_bindgen(method, setter, js_name = id)]
+    pub fn set_id(this: &Label, id: &JsValue);
+
+    /// A graphical point positioned in the 3D scene
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = PointPrimitive)]
+    pub type PointPrimitive;
+
+    #[wasm_bindgen(method, getter, js_name = show)]
+    pub fn show(this: &PointPrimitive) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &PointPrimitive, show: bool);
+
+    #[wasm_bindgen(method, setter, js_name = color)]
+    pub fn set_color(this: &PointPrimitive, color: &Color);
+
+    #[wasm_bindgen(method, setter, js_name = pixelSize)]
+    pub fn set_pixel_size(this: &PointPrimitive, size: f64);
+
+    #[wasm_bindgen(method, setter, js_name = outlineColor)]
+    pub fn set_outline_color(this: &PointPrimitive, color: &Color);
+
+    #[wasm_bindgen(method, setter, js_name = outlineWidth)]
+    pub fn set_outline_width(this: &PointPrimitive, width: f64);
+
+    /// User-defined value returned when the point is picked
+    #[wasm_bindgen(method, getter, js_name = id)]
+    pub fn id(this: &PointPrimitive) -> JsValue;
+
+    #[wasm_bindgen(method, setter, js_name = id)]
+    pub fn set_id(this: &PointPrimitive, id: &JsValue);
+}
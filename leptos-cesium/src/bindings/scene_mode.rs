@@ -0,0 +1,82 @@
+//! Scene modes and map projections
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::JsValue;
+
+/// How the scene is displayed, mirrors `Cesium.SceneMode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SceneMode {
+    /// Globe in 3D perspective
+    #[default]
+    Scene3D,
+    /// Flat map viewed from above
+    Scene2D,
+    /// 2.5D: the flat map in 3D perspective
+    ColumbusView,
+}
+
+impl SceneMode {
+    /// Value of the mode in `Cesium.SceneMode`
+    pub fn to_cesium(self) -> u32 {
+        match self {
+            Self::ColumbusView => 1,
+            Self::Scene2D => 2,
+            Self::Scene3D => 3,
+        }
+    }
+
+    /// Mode for a `Cesium.SceneMode` value, `None` while morphing between modes
+    pub fn from_cesium(value: u32) -> Option<Self> {
+        match value {
+            1 => Some(Self::ColumbusView),
+            2 => Some(Self::Scene2D),
+            3 => Some(Self::Scene3D),
+            _ => None,
+        }
+    }
+}
+
+/// Projection used to flatten the globe in 2D and Columbus view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MapProjection {
+    /// Equirectangular projection, Cesium's default
+    #[default]
+    Geographic,
+    /// Web Mercator, matching most web map imagery
+    WebMercator,
+}
+
+impl MapProjection {
+    /// Create the Cesium projection
+    #[cfg(target_arch = "wasm32")]
+    pub fn to_js(self) -> JsValue {
+        let class = match self {
+            Self::Geographic => "GeographicProjection",
+            Self::WebMercator => "WebMercatorProjection",
+        };
+        crate::bindings::imagery::construct_cesium(class, &js_sys::Array::new())
+            .unwrap_or(JsValue::UNDEFINED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cesium_values_round_trip() {
+        for mode in [
+            SceneMode::Scene3D,
+            SceneMode::Scene2D,
+            SceneMode::ColumbusView,
+        ] {
+            assert_eq!(SceneMode::from_cesium(mode.to_cesium()), Some(mode));
+        }
+        assert_eq!(SceneMode::Scene3D.to_cesium(), 3);
+    }
+
+    #[test]
+    fn morphing_has_no_mode() {
+        assert_eq!(SceneMode::from_cesium(0), None);
+    }
+}
@@ -0,0 +1,124 @@
+//! Cesium ScreenSpaceEventHandler bindings for mouse and touch input.
+
+use crate::bindings::Cartesian2;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Handles user input events on the scene canvas
+    #[wasm_bindgen(js_namespace = Cesium, js_name = ScreenSpaceEventHandler)]
+    pub type ScreenSpaceEventHandler;
+
+    #[wasm_bindgen(constructor, js_namespace = Cesium, js_class = ScreenSpaceEventHandler)]
+    pub fn new(element: &web_sys::HtmlCanvasElement) -> ScreenSpaceEventHandler;
+
+    #[wasm_bindgen(method, js_name = setInputAction)]
+    fn set_input_action_raw(this: &ScreenSpaceEventHandler, action: &js_sys::Function, kind: u32);
+
+    #[wasm_bindgen(method, js_name = setInputAction)]
+    fn set_input_action_with_modifier_raw(
+        this: &ScreenSpaceEventHandler,
+        action: &js_sys::Function,
+        kind: u32,
+        modifier: u32,
+    );
+
+    #[wasm_bindgen(method, js_name = removeInputAction)]
+    fn remove_input_action_raw(this: &ScreenSpaceEventHandler, kind: u32);
+
+    #[wasm_bindgen(method, js_name = removeInputAction)]
+    fn remove_input_action_with_modifier_raw(
+        this: &ScreenSpaceEventHandler,
+        kind: u32,
+        modifier: u32,
+    );
+
+    #[wasm_bindgen(method, js_name = isDestroyed)]
+    pub fn is_destroyed(this: &ScreenSpaceEventHandler) -> bool;
+
+    #[wasm_bindgen(method, js_name = destroy)]
+    pub fn destroy(this: &ScreenSpaceEventHandler);
+
+    /// Event passed to click and down/up actions
+    #[derive(Clone)]
+    pub type PositionedEvent;
+
+    #[wasm_bindgen(method, getter, js_name = position)]
+    pub fn position(this: &PositionedEvent) -> Cartesian2;
+
+    /// Event passed to mouse move actions
+    #[derive(Clone)]
+    pub type MotionEvent;
+
+    #[wasm_bindgen(method, getter, js_name = startPosition)]
+    pub fn start_position(this: &MotionEvent) -> Cartesian2;
+
+    #[wasm_bindgen(method, getter, js_name = endPosition)]
+    pub fn end_position(this: &MotionEvent) -> Cartesian2;
+}
+
+/// Mirrors `Cesium.ScreenSpaceEventType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScreenSpaceEventType {
+    LeftDown = 0,
+    LeftUp = 1,
+    LeftClick = 2,
+    LeftDoubleClick = 3,
+    RightDown = 5,
+    RightUp = 6,
+    RightClick = 7,
+    MiddleDown = 10,
+    MiddleUp = 11,
+    MiddleClick = 12,
+    MouseMove = 15,
+    Wheel = 16,
+    PinchStart = 17,
+    PinchEnd = 18,
+    PinchMove = 19,
+}
+
+/// Mirrors `Cesium.KeyboardEventModifier`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyboardEventModifier {
+    Shift = 0,
+    Ctrl = 1,
+    Alt = 2,
+}
+
+impl ScreenSpaceEventHandler {
+    /// Set the function to execute on an input event
+    pub fn set_input_action(&self, action: &js_sys::Function, kind: ScreenSpaceEventType) {
+        self.set_input_action_raw(action, kind as u32);
+    }
+
+    /// Set the function to execute on an input event while a modifier key is held
+    pub fn set_input_action_with_modifier(
+        &self,
+        action: &js_sys::Function,
+        kind: ScreenSpaceEventType,
+        modifier: KeyboardEventModifier,
+    ) {
+        self.set_input_action_with_modifier_raw(action, kind as u32, modifier as u32);
+    }
+
+    /// Remove the function to execute on an input event
+    pub fn remove_input_action(&self, kind: ScreenSpaceEventType) {
+        self.remove_input_action_raw(kind as u32);
+    }
+
+    /// Remove the function to execute on an input event with a modifier key
+    pub fn remove_input_action_with_modifier(
+        &self,
+        kind: ScreenSpaceEventType,
+        modifier: KeyboardEventModifier,
+    ) {
+        self.remove_input_action_with_modifier_raw(kind as u32, modifier as u32);
+    }
+
+    /// Destroy the handler, doing nothing when it already is
+    pub fn dispose(&self) {
+        if !self.is_destroyed() {
+            self.destroy();
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Screenshots of the rendered scene as encoded image bytes
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{Scene, Viewer};
+#[cfg(target_arch = "wasm32")]
+use crate::core::CesiumError;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+
+/// Image encoding of a screenshot
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ScreenshotFormat {
+    /// Lossless PNG
+    #[default]
+    Png,
+    /// JPEG with a quality between 0.0 and 1.0
+    Jpeg { quality: f64 },
+}
+
+impl ScreenshotFormat {
+    /// MIME type passed to the canvas encoder
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg { .. } => "image/jpeg",
+        }
+    }
+}
+
+/// Options for `capture_screenshot`
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let options = ScreenshotOptions::new()
+///     .format(ScreenshotFormat::Jpeg { quality: 0.9 })
+///     .resolution_scale(2.0);
+/// let bytes = capture_screenshot(&viewer, options).await?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ScreenshotOptions {
+    format: ScreenshotFormat,
+    resolution_scale: Option<f64>,
+}
+
+impl ScreenshotOptions {
+    /// PNG at the viewer's current resolution
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the image encoding (default: PNG)
+    pub fn format(mut self, format: ScreenshotFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Render the captured frame at this multiple of the canvas size in CSS pixels, e.g. 2.0
+    /// for print quality (default: the viewer's current resolution scale)
+    pub fn resolution_scale(mut self, scale: f64) -> Self {
+        self.resolution_scale = Some(scale);
+        self
+    }
+}
+
+/// Render one frame and encode the canvas as image bytes
+///
+/// The canvas is read from the scene's `postRender` event, right after the frame is drawn, so
+/// this works whether or not the viewer was created with `preserveDrawingBuffer`, and in
+/// request render mode. Only the canvas is captured, so Cesium's widgets never appear in the
+/// image. The viewer's resolution scale is restored afterwards.
+#[cfg(target_arch = "wasm32")]
+pub async fn capture_screenshot(
+    viewer: &Viewer,
+    options: ScreenshotOptions,
+) -> Result<Vec<u8>, CesiumError> {
+    let scene = viewer.scene();
+    let original_scale = viewer.resolution_scale();
+    if let Some(scale) = options.resolution_scale {
+        viewer.set_resolution_scale(scale);
+    }
+
+    let blob = render_to_blob(&scene, options.format).await;
+
+    viewer.set_resolution_scale(original_scale);
+    scene.request_render();
+
+    let buffer = wasm_bindgen_futures::JsFuture::from(blob?.array_buffer()).await?;
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+/// Encode the canvas after the next rendered frame
+#[cfg(target_arch = "wasm32")]
+async fn render_to_blob(
+    scene: &Scene,
+    format: ScreenshotFormat,
+) -> Result<web_sys::Blob, CesiumError> {
+    use js_sys::{Function, Promise};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let mut callbacks = None;
+    let promise = Promise::new(&mut |resolve, reject| callbacks = Some((resolve, reject)));
+    let (resolve, reject) = callbacks.expect("Promise executor to run synchronously");
+
+    let canvas = scene.canvas();
+    let quality = match format {
+        ScreenshotFormat::Png => JsValue::UNDEFINED,
+        ScreenshotFormat::Jpeg { quality } => JsValue::from_f64(quality),
+    };
+    let captured = Rc::new(Cell::new(false));
+    let on_post_render = Closure::wrap(Box::new(move || {
+        if captured.replace(true) {
+            return;
+        }
+        // The browser snapshots the canvas synchronously and only encodes asynchronously
+        let resolve = resolve.clone();
+        let on_blob = Closure::once_into_js(move |blob: JsValue| {
+            let _ = resolve.call1(&JsValue::NULL, &blob);
+        })
+        .unchecked_into::<Function>();
+        if let Err(error) =
+            canvas.to_blob_with_type_and_encoder_options(&on_blob, format.mime_type(), &quality)
+        {
+            let _ = reject.call1(&JsValue::NULL, &error);
+        }
+    }) as Box<dyn FnMut()>)
+    .into_js_value()
+    .unchecked_into::<Function>();
+
+    let event = scene.post_render();
+    event.add_event_listener(&on_post_render);
+    scene.request_render();
+    let result = wasm_bindgen_futures::JsFuture::from(promise).await;
+    event.remove_event_listener(&on_post_render);
+
+    // The encoder passes null when the canvas is empty
+    result?
+        .dyn_into::<web_sys::Blob>()
+        .map_err(|_| CesiumError::Js("the canvas could not be encoded".to_string()))
+}
@@ -0,0 +1,127 @@
+//! Highlight style for selected entities
+
+use crate::bindings::Color;
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::Entity;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::JsValue;
+
+/// Graphics whose color is set by the highlight, with the property holding it
+#[cfg(target_arch = "wasm32")]
+const HIGHLIGHTED_COLORS: [(&str, &str); 12] = [
+    ("point", "color"),
+    ("billboard", "color"),
+    ("label", "fillColor"),
+    ("polyline", "material"),
+    ("polygon", "material"),
+    ("rectangle", "material"),
+    ("ellipse", "material"),
+    ("box", "material"),
+    ("corridor", "material"),
+    ("cylinder", "material"),
+    ("ellipsoid", "material"),
+    ("wall", "material"),
+];
+
+/// How selected entities are highlighted
+///
+/// Points, billboards and labels are tinted with the color, shapes and polylines are filled
+/// with it, and models get a silhouette in it. The original values are restored on deselect.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let style = SelectionStyle::new()
+///     .color(Color::magenta().with_alpha(0.8))
+///     .silhouette_size(3.0);
+///
+/// view! { <EntitySelection style=style /> }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelectionStyle {
+    color: (f64, f64, f64, f64),
+    silhouette_size: f64,
+}
+
+impl Default for SelectionStyle {
+    fn default() -> Self {
+        Self {
+            color: (1.0, 1.0, 0.0, 1.0),
+            silhouette_size: 2.0,
+        }
+    }
+}
+
+impl SelectionStyle {
+    /// Yellow highlight with a 2 pixel model silhouette
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the highlight color (default: opaque yellow)
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color.to_rgba();
+        self
+    }
+
+    /// Set the silhouette width of selected models in pixels (default: 2.0)
+    pub fn silhouette_size(mut self, size: f64) -> Self {
+        self.silhouette_size = size;
+        self
+    }
+
+    /// Highlight an entity, returning what is needed to undo it
+    #[cfg(target_arch = "wasm32")]
+    pub fn apply(&self, entity: &Entity) -> Highlight {
+        let (red, green, blue, alpha) = self.color;
+        let color = JsValue::from(Color::new(red, green, blue, alpha));
+        let mut highlight = Highlight {
+            originals: Vec::new(),
+        };
+
+        for (graphics, property) in HIGHLIGHTED_COLORS {
+            if let Some(graphics) = graphics_of(entity, graphics) {
+                highlight.set(graphics, property, &color);
+            }
+        }
+        if let Some(model) = graphics_of(entity, "model") {
+            highlight.set(model.clone(), "silhouetteColor", &color);
+            highlight.set(
+                model,
+                "silhouetteSize",
+                &JsValue::from_f64(self.silhouette_size),
+            );
+        }
+        highlight
+    }
+}
+
+/// A highlighted entity's original graphics properties
+#[cfg(target_arch = "wasm32")]
+pub struct Highlight {
+    originals: Vec<(JsValue, &'static str, JsValue)>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Highlight {
+    /// Put the original properties back
+    pub fn restore(self) {
+        for (graphics, property, original) in self.originals {
+            let _ = js_sys::Reflect::set(&graphics, &JsValue::from_str(property), &original);
+        }
+    }
+
+    fn set(&mut self, graphics: JsValue, property: &'static str, value: &JsValue) {
+        let key = JsValue::from_str(property);
+        let original = js_sys::Reflect::get(&graphics, &key).unwrap_or(JsValue::UNDEFINED);
+        let _ = js_sys::Reflect::set(&graphics, &key, value);
+        self.originals.push((graphics, property, original));
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn graphics_of(entity: &Entity, name: &str) -> Option<JsValue> {
+    js_sys::Reflect::get(entity, &JsValue::from_str(name))
+        .ok()
+        .filter(|graphics| !graphics.is_undefined() && !graphics.is_null())
+}
@@ -0,0 +1,215 @@
+//! Cesium terrain provider descriptions
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, JsValue};
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::imagery::{call_provider_factory, construct_provider};
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{Cartographic, Scene};
+#[cfg(target_arch = "wasm32")]
+use crate::core::CesiumError;
+
+/// The source of terrain heights for the globe
+///
+/// Providers that Cesium creates asynchronously (`fromUrl`/`fromIonAssetId`) are handled
+/// transparently.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum TerrainProvider {
+    /// Smooth WGS84 ellipsoid without any heights; needs no network access
+    #[default]
+    Ellipsoid,
+    /// Quantized-mesh terrain from a Cesium terrain server (e.g. a self-hosted tile set)
+    CesiumTerrain {
+        url: String,
+        /// Request per-vertex normals for terrain lighting
+        request_vertex_normals: bool,
+        /// Request the water mask used to render ocean waves
+        request_water_mask: bool,
+    },
+    /// Terrain asset hosted on Cesium Ion
+    IonAsset(u32),
+    /// Cesium World Terrain from Cesium Ion
+    World,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl TerrainProvider {
+    /// Start creating the provider, resolving to the Cesium terrain provider instance
+    pub fn create(&self) -> js_sys::Promise {
+        use js_sys::{Array, Object, Reflect};
+
+        match self {
+            TerrainProvider::Ellipsoid => {
+                construct_provider("EllipsoidTerrainProvider", &JsValue::UNDEFINED)
+            }
+            TerrainProvider::CesiumTerrain { url, .. } => {
+                let options = Object::new();
+                for (name, value) in self.factory_options().into_iter().flatten() {
+                    let _ = Reflect::set(
+                        &options,
+                        &JsValue::from_str(name),
+                        &JsValue::from_bool(value),
+                    );
+                }
+                call_provider_factory(
+                    "CesiumTerrainProvider",
+                    "fromUrl",
+                    &Array::of2(&JsValue::from_str(url), &options),
+                )
+            }
+            TerrainProvider::IonAsset(asset_id) => call_provider_factory(
+                "CesiumTerrainProvider",
+                "fromIonAssetId",
+                &Array::of1(&JsValue::from_f64(*asset_id as f64)),
+            ),
+            TerrainProvider::World => call_cesium_async("createWorldTerrainAsync", &Array::new()),
+        }
+    }
+}
+
+impl TerrainProvider {
+    /// Options handed to the provider's Cesium factory, if it takes any
+    #[cfg(any(target_arch = "wasm32", test))]
+    fn factory_options(&self) -> Option<[(&'static str, bool); 2]> {
+        match self {
+            TerrainProvider::CesiumTerrain {
+                request_vertex_normals,
+                request_water_mask,
+                ..
+            } => Some([
+                ("requestVertexNormals", *request_vertex_normals),
+                ("requestWaterMask", *request_water_mask),
+            ]),
+            TerrainProvider::Ellipsoid | TerrainProvider::IonAsset(_) | TerrainProvider::World => {
+                None
+            }
+        }
+    }
+}
+
+/// Sample terrain heights at the most detailed level the terrain provider has available
+///
+/// Returns new positions with the sampled heights; the input positions are left untouched.
+/// Fails if a tile cannot be loaded or the provider has no tile availability (e.g. the
+/// ellipsoid).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let terrain = viewer.scene().terrain_provider();
+/// let summit = Cartographic::from_degrees(86.925, 27.9881, 0.0);
+/// let sampled = sample_terrain_most_detailed(&terrain, &[summit]).await?;
+/// log!("height: {}", sampled[0].height());
+/// ```
+#[cfg(target_arch = "wasm32")]
+pub async fn sample_terrain_most_detailed(
+    terrain_provider: &JsValue,
+    positions: &[Cartographic],
+) -> Result<Vec<Cartographic>, CesiumError> {
+    use js_sys::Array;
+
+    let args = Array::of3(terrain_provider, &copy_positions(positions), &JsValue::TRUE);
+    sampled_positions(call_cesium_async("sampleTerrainMostDetailed", &args)).await
+}
+
+/// Sample terrain heights at the given level of detail
+///
+/// Returns new positions with the sampled heights; the input positions are left untouched.
+/// Fails if a tile at that level cannot be loaded.
+#[cfg(target_arch = "wasm32")]
+pub async fn sample_terrain(
+    terrain_provider: &JsValue,
+    level: u32,
+    positions: &[Cartographic],
+) -> Result<Vec<Cartographic>, CesiumError> {
+    use js_sys::Array;
+
+    let args = Array::new();
+    args.push(terrain_provider);
+    args.push(&JsValue::from(level));
+    args.push(&copy_positions(positions));
+    args.push(&JsValue::TRUE);
+    sampled_positions(call_cesium_async("sampleTerrain", &args)).await
+}
+
+/// Height of the terrain currently loaded by the globe at the position
+///
+/// This is synchronous and cheap enough to call on every mouse move, but only reflects the
+/// tiles rendered so far. Returns `None` when the viewer has no globe or no tile covering the
+/// position is loaded.
+#[cfg(target_arch = "wasm32")]
+pub fn globe_height(scene: &Scene, position: &Cartographic) -> Option<f64> {
+    scene.globe()?.get_height(position)
+}
+
+/// Internal helper copying positions since Cesium writes the sampled heights in place
+#[cfg(target_arch = "wasm32")]
+fn copy_positions(positions: &[Cartographic]) -> js_sys::Array {
+    positions
+        .iter()
+        .map(|position| {
+            JsValue::from(Cartographic::new(
+                position.longitude(),
+                position.latitude(),
+                position.height(),
+            ))
+        })
+        .collect()
+}
+
+/// Internal helper awaiting a sampling promise
+#[cfg(target_arch = "wasm32")]
+async fn sampled_positions(promise: js_sys::Promise) -> Result<Vec<Cartographic>, CesiumError> {
+    let positions = wasm_bindgen_futures::JsFuture::from(promise).await?;
+    Ok(positions
+        .unchecked_into::<js_sys::Array>()
+        .iter()
+        .map(|position| position.unchecked_into::<Cartographic>())
+        .collect())
+}
+
+/// Internal helper to call an async function on the Cesium global, e.g. `Cesium[name](...args)`
+#[cfg(target_arch = "wasm32")]
+fn call_cesium_async(name: &str, args: &js_sys::Array) -> js_sys::Promise {
+    use js_sys::{Function, Promise, Reflect, global};
+
+    let cesium = Reflect::get(&global(), &JsValue::from_str("Cesium"))
+        .expect("Cesium global to be available");
+    let function: Function = Reflect::get(&cesium, &JsValue::from_str(name))
+        .ok()
+        .and_then(|f| f.dyn_into().ok())
+        .unwrap_or_else(|| panic!("Cesium.{} to be callable", name));
+
+    // Errors surface through the rejected promise
+    match Reflect::apply(&function, &cesium, args) {
+        Ok(result) => Promise::resolve(&result),
+        Err(err) => Promise::reject(&err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cesium_terrain_requests_extensions() {
+        let provider = TerrainProvider::CesiumTerrain {
+            url: "/terrain".to_string(),
+            request_vertex_normals: true,
+            request_water_mask: false,
+        };
+        assert_eq!(
+            provider.factory_options(),
+            Some([("requestVertexNormals", true), ("requestWaterMask", false)])
+        );
+    }
+
+    #[test]
+    fn other_providers_take_no_options() {
+        assert_eq!(TerrainProvider::default(), TerrainProvider::Ellipsoid);
+        assert_eq!(TerrainProvider::Ellipsoid.factory_options(), None);
+        assert_eq!(TerrainProvider::IonAsset(1).factory_options(), None);
+        assert_eq!(TerrainProvider::World.factory_options(), None);
+    }
+}
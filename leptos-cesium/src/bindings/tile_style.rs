@@ -0,0 +1,437 @@
+//! Typed builder for `Cesium3DTileStyle` declarative styling
+//!
+//! Styles are written in the [3D Tiles styling language] and assembled as JSON, so they can be
+//! built and inspected without a browser.
+//!
+//! [3D Tiles styling language]: https://github.com/CesiumGS/3d-tiles/tree/main/specification/Styling
+
+use crate::bindings::Color;
+use serde_json::{Map, Value};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A style applied to the features of a 3D Tiles tileset
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Cesium3DTileStyle)]
+    pub type Cesium3DTileStyle;
+
+    /// Create a style from its JSON description
+    #[wasm_bindgen(constructor, js_namespace = Cesium, js_class = Cesium3DTileStyle)]
+    pub fn new(style: &JsValue) -> Cesium3DTileStyle;
+}
+
+/// An expression in the 3D Tiles styling language
+///
+/// Build expressions with [`expr`], [`var`], [`string`], [`color`] and friends, or convert
+/// numbers, booleans and [`Color`]s with `into()`. Comparison and logic methods wrap their operands in
+/// parentheses, so combined expressions keep their intended precedence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr(String);
+
+impl Expr {
+    /// The expression source text
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn binary(self, operator: &str, other: impl Into<Expr>) -> Expr {
+        Expr(format!("({} {} {})", self.0, operator, other.into().0))
+    }
+
+    /// `self > other`
+    pub fn gt(self, other: impl Into<Expr>) -> Expr {
+        self.binary(">", other)
+    }
+
+    /// `self >= other`
+    pub fn ge(self, other: impl Into<Expr>) -> Expr {
+        self.binary(">=", other)
+    }
+
+    /// `self < other`
+    pub fn lt(self, other: impl Into<Expr>) -> Expr {
+        self.binary("<", other)
+    }
+
+    /// `self <= other`
+    pub fn le(self, other: impl Into<Expr>) -> Expr {
+        self.binary("<=", other)
+    }
+
+    /// `self === other`
+    pub fn equals(self, other: impl Into<Expr>) -> Expr {
+        self.binary("===", other)
+    }
+
+    /// `self !== other`
+    pub fn not_equals(self, other: impl Into<Expr>) -> Expr {
+        self.binary("!==", other)
+    }
+
+    /// `self && other`
+    pub fn and(self, other: impl Into<Expr>) -> Expr {
+        self.binary("&&", other)
+    }
+
+    /// `self || other`
+    pub fn or(self, other: impl Into<Expr>) -> Expr {
+        self.binary("||", other)
+    }
+
+    /// `!self`
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Expr {
+        Expr(format!("!({})", self.0))
+    }
+}
+
+/// Numbers are clamped to finite values, which the style language can parse: infinities
+/// become the largest finite number of their sign and NaN becomes 0.
+impl From<f64> for Expr {
+    fn from(value: f64) -> Self {
+        let value = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(f64::MIN, f64::MAX)
+        };
+        Expr(value.to_string())
+    }
+}
+
+impl From<i32> for Expr {
+    fn from(value: i32) -> Self {
+        Expr(value.to_string())
+    }
+}
+
+impl From<bool> for Expr {
+    fn from(value: bool) -> Self {
+        Expr(value.to_string())
+    }
+}
+
+impl From<Color> for Expr {
+    fn from(color: Color) -> Self {
+        let (red, green, blue, alpha) = color.to_rgba();
+        rgba_of(red, green, blue, alpha)
+    }
+}
+
+/// A raw style-language expression, e.g. `expr("${height} > 100")`
+pub fn expr(source: impl Into<String>) -> Expr {
+    Expr(source.into())
+}
+
+/// A feature property or define, e.g. `var("height")` for `${height}`
+pub fn var(name: &str) -> Expr {
+    Expr(format!("${{{}}}", name))
+}
+
+/// A string literal, quoted and escaped for the style language
+pub fn string(value: &str) -> Expr {
+    Expr(format!(
+        "'{}'",
+        value.replace('\\', "\\\\").replace('\'', "\\'")
+    ))
+}
+
+/// A CSS color by keyword or hex string, e.g. `color("red")` or `color("#67ADDF")`
+///
+/// Prefer passing a [`Color`] where an expression is expected; this form takes any CSS
+/// color the style language understands.
+pub fn color(css: &str) -> Expr {
+    Expr(format!("color({})", string(css).0))
+}
+
+/// A CSS color with an alpha from 0.0 (transparent) to 1.0 (opaque)
+pub fn color_alpha(css: &str, alpha: impl Into<Expr>) -> Expr {
+    Expr(format!("color({}, {})", string(css).0, alpha.into().0))
+}
+
+/// A color from red, green and blue components in 0-255
+pub fn rgb(red: impl Into<Expr>, green: impl Into<Expr>, blue: impl Into<Expr>) -> Expr {
+    Expr(format!(
+        "rgb({}, {}, {})",
+        red.into().0,
+        green.into().0,
+        blue.into().0
+    ))
+}
+
+/// A color from red, green and blue components in 0-255 and an alpha in 0.0-1.0
+pub fn rgba(
+    red: impl Into<Expr>,
+    green: impl Into<Expr>,
+    blue: impl Into<Expr>,
+    alpha: impl Into<Expr>,
+) -> Expr {
+    Expr(format!(
+        "rgba({}, {}, {}, {})",
+        red.into().0,
+        green.into().0,
+        blue.into().0,
+        alpha.into().0
+    ))
+}
+
+/// Internal helper turning 0.0-1.0 color components into an `rgba` expression
+fn rgba_of(red: f64, green: f64, blue: f64, alpha: f64) -> Expr {
+    let byte = |component: f64| (component * 255.0).round();
+    rgba(byte(red), byte(green), byte(blue), alpha)
+}
+
+/// One branch of a conditional style: `then` applies to features where `when` is true
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    when: Expr,
+    then: Expr,
+}
+
+/// A conditional branch, e.g. `cond(expr("${height} > 100"), color("red"))`
+///
+/// Conditions are evaluated in order and the first match wins; end with `cond(true, ...)`
+/// for a fallback.
+pub fn cond(when: impl Into<Expr>, then: impl Into<Expr>) -> Condition {
+    Condition {
+        when: when.into(),
+        then: then.into(),
+    }
+}
+
+/// The value of a style property: a single expression or a list of conditions
+#[derive(Debug, Clone, PartialEq)]
+pub enum StyleExpression {
+    Expr(Expr),
+    Conditions(Vec<Condition>),
+}
+
+impl StyleExpression {
+    fn to_json(&self) -> Value {
+        match self {
+            StyleExpression::Expr(expr) => Value::String(expr.0.clone()),
+            StyleExpression::Conditions(conditions) => {
+                let conditions = conditions
+                    .iter()
+                    .map(|condition| {
+                        Value::Array(vec![
+                            Value::String(condition.when.0.clone()),
+                            Value::String(condition.then.0.clone()),
+                        ])
+                    })
+                    .collect();
+                let mut object = Map::new();
+                object.insert("conditions".to_string(), Value::Array(conditions));
+                Value::Object(object)
+            }
+        }
+    }
+}
+
+impl<T: Into<Expr>> From<T> for StyleExpression {
+    fn from(expr: T) -> Self {
+        StyleExpression::Expr(expr.into())
+    }
+}
+
+impl From<Vec<Condition>> for StyleExpression {
+    fn from(conditions: Vec<Condition>) -> Self {
+        StyleExpression::Conditions(conditions)
+    }
+}
+
+impl<const N: usize> From<[Condition; N]> for StyleExpression {
+    fn from(conditions: [Condition; N]) -> Self {
+        StyleExpression::Conditions(conditions.into())
+    }
+}
+
+/// Builder for a `Cesium3DTileStyle`
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use leptos_cesium::bindings::tile_style::{color_alpha, cond, expr, var};
+///
+/// let style = TileStyle::new()
+///     .define("height", expr("Number(${Height})"))
+///     .color([
+///         cond(var("height").gt(100.0), Color::red()),
+///         cond(var("height").gt(50.0), color_alpha("orange", 0.8)),
+///         cond(true, Color::white()),
+///     ])
+///     .show(var("height").gt(0))
+///     .meta("description", expr("'Building ' + ${id}"));
+///
+/// view! { <Tileset3D url=url style=style /> }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TileStyle {
+    defines: Vec<(String, Expr)>,
+    show: Option<StyleExpression>,
+    color: Option<StyleExpression>,
+    point_size: Option<StyleExpression>,
+    meta: Vec<(String, Expr)>,
+}
+
+impl TileStyle {
+    /// Create an empty style, which renders features unchanged
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Define a named expression that other expressions can use as `${name}`
+    pub fn define(mut self, name: impl Into<String>, expr: Expr) -> Self {
+        self.defines.push((name.into(), expr));
+        self
+    }
+
+    /// Set which features are shown
+    pub fn show(mut self, show: impl Into<StyleExpression>) -> Self {
+        self.show = Some(show.into());
+        self
+    }
+
+    /// Set the feature color
+    pub fn color(mut self, color: impl Into<StyleExpression>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the size of point cloud points in pixels
+    pub fn point_size(mut self, point_size: impl Into<StyleExpression>) -> Self {
+        self.point_size = Some(point_size.into());
+        self
+    }
+
+    /// Add a non-visual property evaluated per feature, e.g. for picking info
+    pub fn meta(mut self, name: impl Into<String>, expr: Expr) -> Self {
+        self.meta.push((name.into(), expr));
+        self
+    }
+
+    /// The style as the JSON object accepted by `Cesium3DTileStyle`
+    pub fn to_json(&self) -> Value {
+        let expressions = |entries: &[(String, Expr)]| {
+            entries
+                .iter()
+                .map(|(name, expr)| (name.clone(), Value::String(expr.0.clone())))
+                .collect::<Map<_, _>>()
+        };
+
+        let mut style = Map::new();
+        if !self.defines.is_empty() {
+            style.insert(
+                "defines".to_string(),
+                Value::Object(expressions(&self.defines)),
+            );
+        }
+        if let Some(show) = &self.show {
+            style.insert("show".to_string(), show.to_json());
+        }
+        if let Some(color) = &self.color {
+            style.insert("color".to_string(), color.to_json());
+        }
+        if let Some(point_size) = &self.point_size {
+            style.insert("pointSize".to_string(), point_size.to_json());
+        }
+        if !self.meta.is_empty() {
+            style.insert("meta".to_string(), Value::Object(expressions(&self.meta)));
+        }
+        Value::Object(style)
+    }
+
+    /// Build the Cesium3DTileStyle
+    #[cfg(target_arch = "wasm32")]
+    pub fn build(&self) -> Cesium3DTileStyle {
+        let json =
+            js_sys::JSON::parse(&self.to_json().to_string()).expect("TileStyle JSON to be valid");
+        Cesium3DTileStyle::new(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn empty_style_is_empty_object() {
+        assert_eq!(TileStyle::new().to_json(), json!({}));
+    }
+
+    #[test]
+    fn color_conditions() {
+        let style = TileStyle::new().color([
+            cond(expr("${height} > 100"), color("red")),
+            cond(true, color_alpha("white", 0.5)),
+        ]);
+
+        assert_eq!(
+            style.to_json(),
+            json!({
+                "color": {
+                    "conditions": [
+                        ["${height} > 100", "color('red')"],
+                        ["true", "color('white', 0.5)"],
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn show_point_size_defines_and_meta() {
+        let style = TileStyle::new()
+            .define("height", expr("Number(${Height})"))
+            .show(
+                var("height")
+                    .gt(0)
+                    .and(var("type").not_equals(string("shed"))),
+            )
+            .point_size(4.0)
+            .meta("description", expr("'Height: ' + ${height}"));
+
+        assert_eq!(
+            style.to_json(),
+            json!({
+                "defines": { "height": "Number(${Height})" },
+                "show": "((${height} > 0) && (${type} !== 'shed'))",
+                "pointSize": "4",
+                "meta": { "description": "'Height: ' + ${height}" },
+            })
+        );
+    }
+
+    #[test]
+    fn expression_helpers() {
+        assert_eq!(var("height").as_str(), "${height}");
+        assert_eq!(string("it's").as_str(), r"'it\'s'");
+        assert_eq!(rgb(255, 0, 0).as_str(), "rgb(255, 0, 0)");
+        assert_eq!(rgba(0, 0, 255, 0.5).as_str(), "rgba(0, 0, 255, 0.5)");
+        assert_eq!(
+            var("floors")
+                .ge(3)
+                .or(var("roof").equals(true))
+                .not()
+                .as_str(),
+            "!(((${floors} >= 3) || (${roof} === true)))"
+        );
+    }
+
+    #[test]
+    fn non_finite_numbers_are_clamped() {
+        assert_eq!(Expr::from(f64::NAN).as_str(), "0");
+        assert_eq!(Expr::from(f64::INFINITY), Expr::from(f64::MAX));
+        assert_eq!(Expr::from(f64::NEG_INFINITY), Expr::from(f64::MIN));
+        assert_eq!(Expr::from(2.5).as_str(), "2.5");
+    }
+
+    #[test]
+    fn color_components_become_rgba() {
+        assert_eq!(
+            rgba_of(1.0, 0.5, 0.0, 0.25).as_str(),
+            "rgba(255, 128, 0, 0.25)"
+        );
+    }
+}
@@ -2,15 +2,114 @@
 
 use wasm_bindgen::prelude::*;
 
+use crate::bindings::{BoundingSphere, Color, Event, Matrix4};
+
 #[wasm_bindgen]
 extern "C" {
     /// Cesium3DTileset for loading 3D tile data
+    #[derive(Clone)]
     #[wasm_bindgen(js_namespace = Cesium, js_name = Cesium3DTileset)]
     pub type Cesium3DTileset;
 
+    /// Load a tileset from the URL of its `tileset.json`
+    #[wasm_bindgen(static_method_of = Cesium3DTileset, js_name = fromUrl)]
+    pub fn from_url(url: &str, options: &JsValue) -> js_sys::Promise;
+
+    /// Load a tileset hosted on Cesium Ion
+    #[wasm_bindgen(static_method_of = Cesium3DTileset, js_name = fromIonAssetId)]
+    pub fn from_ion_asset_id(asset_id: u32, options: &JsValue) -> js_sys::Promise;
+
     #[wasm_bindgen(method, js_name = destroy)]
     pub fn destroy(this: &Cesium3DTileset);
 
+    #[wasm_bindgen(method, js_name = isDestroyed)]
+    pub fn is_destroyed(this: &Cesium3DTileset) -> bool;
+
+    #[wasm_bindgen(method, getter, js_name = show)]
+    pub fn show(this: &Cesium3DTileset) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &Cesium3DTileset, value: bool);
+
+    #[wasm_bindgen(method, getter, js_name = maximumScreenSpaceError)]
+    pub fn maximum_screen_space_error(this: &Cesium3DTileset) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = maximumScreenSpaceError)]
+    pub fn set_maximum_screen_space_error(this: &Cesium3DTileset, value: f64);
+
+    /// Transform applied to the whole tileset
+    #[wasm_bindgen(method, getter, js_name = modelMatrix)]
+    pub fn model_matrix(this: &Cesium3DTileset) -> Matrix4;
+
+    #[wasm_bindgen(method, setter, js_name = modelMatrix)]
+    pub fn set_model_matrix(this: &Cesium3DTileset, value: &Matrix4);
+
+    /// Style applied to the tileset's features; `undefined` renders them unstyled
+    #[wasm_bindgen(method, setter, js_name = style)]
+    pub fn set_style(this: &Cesium3DTileset, style: &JsValue);
+
+    /// Raised with the number of pending tile requests and tiles being processed
+    #[wasm_bindgen(method, getter, js_name = loadProgress)]
+    pub fn load_progress(this: &Cesium3DTileset) -> Event;
+
+    /// Raised when all tiles meeting the screen space error this frame are loaded
+    #[wasm_bindgen(method, getter, js_name = allTilesLoaded)]
+    pub fn all_tiles_loaded(this: &Cesium3DTileset) -> Event;
+
+    /// Raised once, when the tiles for the initial view are loaded
+    #[wasm_bindgen(method, getter, js_name = initialTilesLoaded)]
+    pub fn initial_tiles_loaded(this: &Cesium3DTileset) -> Event;
+
+    /// Raised with a `TileFailedError` when a tile fails to load
+    #[wasm_bindgen(method, getter, js_name = tileFailed)]
+    pub fn tile_failed(this: &Cesium3DTileset) -> Event;
+
+    /// Bounding sphere of the root tile, e.g. for flying to the tileset
+    #[wasm_bindgen(method, getter, js_name = boundingSphere)]
+    pub fn bounding_sphere(this: &Cesium3DTileset) -> BoundingSphere;
+
+    /// A feature (e.g. a building) of a tileset, as returned by picking
+    #[derive(Clone)]
+    #[wasm_bindgen(js_namespace = Cesium, js_name = Cesium3DTileFeature)]
+    pub type Cesium3DTileFeature;
+
+    /// Returns the value of the feature's property, or `undefined` if it has none by that name
+    #[wasm_bindgen(method, js_name = getProperty)]
+    pub fn get_property(this: &Cesium3DTileFeature, name: &str) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = getPropertyIds)]
+    fn get_property_ids_raw(this: &Cesium3DTileFeature) -> js_sys::Array;
+
+    /// Color the feature is blended with
+    #[wasm_bindgen(method, getter, js_name = color)]
+    pub fn color(this: &Cesium3DTileFeature) -> Color;
+
+    #[wasm_bindgen(method, setter, js_name = color)]
+    pub fn set_color(this: &Cesium3DTileFeature, value: &Color);
+
+    /// Like `set_color`, but fails instead of throwing when the feature's tile was unloaded
+    #[wasm_bindgen(method, setter, js_name = color, catch)]
+    pub fn try_set_color(this: &Cesium3DTileFeature, value: &Color) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method, getter, js_name = show)]
+    pub fn show(this: &Cesium3DTileFeature) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = show)]
+    pub fn set_show(this: &Cesium3DTileFeature, value: bool);
+
+    /// The tileset the feature belongs to
+    #[wasm_bindgen(method, getter, js_name = tileset)]
+    pub fn tileset(this: &Cesium3DTileFeature) -> Cesium3DTileset;
+
+    /// Argument of the `tileFailed` event
+    pub type TileFailedError;
+
+    #[wasm_bindgen(method, getter, js_name = url)]
+    pub fn url(this: &TileFailedError) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = message)]
+    pub fn message(this: &TileFailedError) -> String;
+
     /// Create Google Photorealistic 3D Tiles tileset
     ///
     /// # Parameters
@@ -28,6 +127,23 @@ extern "C" {
     ) -> js_sys::Promise;
 }
 
+impl Cesium3DTileFeature {
+    /// Names of all properties of the feature
+    pub fn get_property_ids(&self) -> Vec<String> {
+        self.get_property_ids_raw()
+            .iter()
+            .filter_map(|id| id.as_string())
+            .collect()
+    }
+
+    /// Whether the feature belongs to `tileset`
+    pub fn belongs_to(&self, tileset: &Cesium3DTileset) -> bool {
+        let own: JsValue = self.tileset().into();
+        let tileset: &JsValue = tileset.as_ref();
+        &own == tileset
+    }
+}
+
 /// Options for createGooglePhotorealistic3DTileset API options parameter
 #[cfg(target_arch = "wasm32")]
 pub struct GooglePhotorealistic3DTilesApiOptions {
@@ -66,8 +182,37 @@ impl GooglePhotorealistic3DTilesApiOptions {
     }
 }
 
-/// Options for Cesium3DTileset constructor (subset of most commonly used options)
-#[cfg(target_arch = "wasm32")]
+/// Whether a tileset casts and receives shadows, mirrors `Cesium.ShadowMode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ShadowMode {
+    /// Neither casts nor receives shadows
+    Disabled,
+    /// Casts and receives shadows
+    #[default]
+    Enabled,
+    /// Only casts shadows
+    CastOnly,
+    /// Only receives shadows
+    ReceiveOnly,
+}
+
+impl ShadowMode {
+    /// Value of the mode in `Cesium.ShadowMode`
+    pub fn to_cesium(self) -> u32 {
+        match self {
+            Self::Disabled => 0,
+            Self::Enabled => 1,
+            Self::CastOnly => 2,
+            Self::ReceiveOnly => 3,
+        }
+    }
+}
+
+/// Options for the Cesium3DTileset constructor
+///
+/// Options left at `None` keep Cesium's default. `show`, `modelMatrix`, clipping and style are
+/// set on the loaded tileset by the components instead.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Cesium3DTilesetOptions {
     /// Size in bytes for tile cache. Default in Google tiles: 1536 * 1024 * 1024
     pub cache_bytes: Option<u32>,
@@ -77,9 +222,65 @@ pub struct Cesium3DTilesetOptions {
     pub enable_collision: Option<bool>,
     /// Maximum screen space error for LOD. Default: 16
     pub maximum_screen_space_error: Option<f64>,
+    /// Whether the tileset casts and receives shadows. Default: enabled
+    pub shadows: Option<ShadowMode>,
+    /// Cull tiles whose children are all outside the view. Default: true
+    pub cull_with_children_bounds: Option<bool>,
+    /// Skip requests for tiles likely to be out of view once the camera stops. Default: true
+    pub cull_requests_while_moving: Option<bool>,
+    /// How much camera movement skips requests while moving. Default: 60
+    pub cull_requests_while_moving_multiplier: Option<f64>,
+    /// Keep loading tiles while the tileset is hidden. Default: false
+    pub preload_when_hidden: Option<bool>,
+    /// Load tiles at the destination of a camera flight while flying. Default: true
+    pub preload_flight_destinations: Option<bool>,
+    /// Load leaf tiles first. Default: false
+    pub prefer_leaves: Option<bool>,
+    /// Lower the detail of tiles far away in street-level views. Default: true
+    pub dynamic_screen_space_error: Option<bool>,
+    /// Density of the fog used for the dynamic screen space error. Default: 2.0e-4
+    pub dynamic_screen_space_error_density: Option<f64>,
+    /// How strongly the dynamic screen space error lowers detail. Default: 24
+    pub dynamic_screen_space_error_factor: Option<f64>,
+    /// Fraction of the tileset height where the dynamic screen space error starts. Default: 0.25
+    pub dynamic_screen_space_error_height_falloff: Option<f64>,
+    /// Load tiles at this fraction of the screen resolution first, between 0 and 0.5.
+    /// Default: 0.3
+    pub progressive_resolution_height_fraction: Option<f64>,
+    /// Load tiles at the center of the screen first. Default: true
+    pub foveated_screen_space_error: Option<bool>,
+    /// Size of the center cone that is loaded first, between 0 and 1. Default: 0.1
+    pub foveated_cone_size: Option<f64>,
+    /// Screen space error relaxation of tiles at the edge of the cone. Default: 0
+    pub foveated_minimum_screen_space_error_relaxation: Option<f64>,
+    /// Seconds to wait after the camera stops before loading tiles outside the cone.
+    /// Default: 0.2
+    pub foveated_time_delay: Option<f64>,
+    /// Skip levels of detail while loading. Default: false
+    pub skip_level_of_detail: Option<bool>,
+    /// Screen space error that must be reached before skipping levels. Default: 1024
+    pub base_screen_space_error: Option<f64>,
+    /// Multiplier of the screen space error between skipped levels. Default: 16
+    pub skip_screen_space_error_factor: Option<f64>,
+    /// Minimum number of levels to skip. Default: 1
+    pub skip_levels: Option<u32>,
+    /// Load only the tiles meeting the screen space error, skipping all levels in between.
+    /// Default: false
+    pub immediately_load_desired_level_of_detail: Option<bool>,
+    /// Load the siblings of visible tiles while skipping levels. Default: false
+    pub load_siblings: Option<bool>,
+    /// Cull back faces of the tileset's geometry. Default: true
+    pub back_face_culling: Option<bool>,
+    /// Keep the outlines of models with `CESIUM_primitive_outline` available. Default: true
+    pub enable_show_outline: Option<bool>,
+    /// Draw the outlines of models with `CESIUM_primitive_outline`. Default: true
+    pub show_outline: Option<bool>,
+    /// Show the tileset's credits on screen instead of in the credits lightbox. Default: false
+    pub show_credits_on_screen: Option<bool>,
+    /// Allow picking features of the tileset. Default: true
+    pub enable_pick: Option<bool>,
 }
 
-#[cfg(target_arch = "wasm32")]
 impl Default for Cesium3DTilesetOptions {
     fn default() -> Self {
         Self {
@@ -87,47 +288,202 @@ impl Default for Cesium3DTilesetOptions {
             maximum_cache_overflow_bytes: Some(1024 * 1024 * 1024),
             enable_collision: Some(true),
             maximum_screen_space_error: None,
+            shadows: None,
+            cull_with_children_bounds: None,
+            cull_requests_while_moving: None,
+            cull_requests_while_moving_multiplier: None,
+            preload_when_hidden: None,
+            preload_flight_destinations: None,
+            prefer_leaves: None,
+            dynamic_screen_space_error: None,
+            dynamic_screen_space_error_density: None,
+            dynamic_screen_space_error_factor: None,
+            dynamic_screen_space_error_height_falloff: None,
+            progressive_resolution_height_fraction: None,
+            foveated_screen_space_error: None,
+            foveated_cone_size: None,
+            foveated_minimum_screen_space_error_relaxation: None,
+            foveated_time_delay: None,
+            skip_level_of_detail: None,
+            base_screen_space_error: None,
+            skip_screen_space_error_factor: None,
+            skip_levels: None,
+            immediately_load_desired_level_of_detail: None,
+            load_siblings: None,
+            back_face_culling: None,
+            enable_show_outline: None,
+            show_outline: None,
+            show_credits_on_screen: None,
+            enable_pick: None,
         }
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+/// Value of a tileset constructor option
+#[cfg(any(target_arch = "wasm32", test))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OptionValue {
+    Bool(bool),
+    Number(f64),
+}
+
 impl Cesium3DTilesetOptions {
+    /// Names and values of the set options, as Cesium expects them
+    #[cfg(any(target_arch = "wasm32", test))]
+    fn entries(&self) -> Vec<(&'static str, OptionValue)> {
+        let numbers = [
+            ("cacheBytes", self.cache_bytes.map(f64::from)),
+            (
+                "maximumCacheOverflowBytes",
+                self.maximum_cache_overflow_bytes.map(f64::from),
+            ),
+            ("maximumScreenSpaceError", self.maximum_screen_space_error),
+            (
+                "shadows",
+                self.shadows.map(|shadows| f64::from(shadows.to_cesium())),
+            ),
+            (
+                "cullRequestsWhileMovingMultiplier",
+                self.cull_requests_while_moving_multiplier,
+            ),
+            (
+                "dynamicScreenSpaceErrorDensity",
+                self.dynamic_screen_space_error_density,
+            ),
+            (
+                "dynamicScreenSpaceErrorFactor",
+                self.dynamic_screen_space_error_factor,
+            ),
+            (
+                "dynamicScreenSpaceErrorHeightFalloff",
+                self.dynamic_screen_space_error_height_falloff,
+            ),
+            (
+                "progressiveResolutionHeightFraction",
+                self.progressive_resolution_height_fraction,
+            ),
+            ("foveatedConeSize", self.foveated_cone_size),
+            (
+                "foveatedMinimumScreenSpaceErrorRelaxation",
+                self.foveated_minimum_screen_space_error_relaxation,
+            ),
+            ("foveatedTimeDelay", self.foveated_time_delay),
+            ("baseScreenSpaceError", self.base_screen_space_error),
+            (
+                "skipScreenSpaceErrorFactor",
+                self.skip_screen_space_error_factor,
+            ),
+            ("skipLevels", self.skip_levels.map(f64::from)),
+        ];
+        let flags = [
+            ("enableCollision", self.enable_collision),
+            ("cullWithChildrenBounds", self.cull_with_children_bounds),
+            ("cullRequestsWhileMoving", self.cull_requests_while_moving),
+            ("preloadWhenHidden", self.preload_when_hidden),
+            (
+                "preloadFlightDestinations",
+                self.preload_flight_destinations,
+            ),
+            ("preferLeaves", self.prefer_leaves),
+            ("dynamicScreenSpaceError", self.dynamic_screen_space_error),
+            ("foveatedScreenSpaceError", self.foveated_screen_space_error),
+            ("skipLevelOfDetail", self.skip_level_of_detail),
+            (
+                "immediatelyLoadDesiredLevelOfDetail",
+                self.immediately_load_desired_level_of_detail,
+            ),
+            ("loadSiblings", self.load_siblings),
+            ("backFaceCulling", self.back_face_culling),
+            ("enableShowOutline", self.enable_show_outline),
+            ("showOutline", self.show_outline),
+            ("showCreditsOnScreen", self.show_credits_on_screen),
+            ("enablePick", self.enable_pick),
+        ];
+
+        numbers
+            .into_iter()
+            .filter_map(|(name, value)| Some((name, OptionValue::Number(value?))))
+            .chain(
+                flags
+                    .into_iter()
+                    .filter_map(|(name, value)| Some((name, OptionValue::Bool(value?)))),
+            )
+            .collect()
+    }
+
+    #[cfg(target_arch = "wasm32")]
     pub fn to_js_value(&self) -> JsValue {
         let obj = js_sys::Object::new();
 
-        if let Some(cache_bytes) = self.cache_bytes {
-            let _ = js_sys::Reflect::set(
-                &obj,
-                &JsValue::from_str("cacheBytes"),
-                &JsValue::from_f64(cache_bytes as f64),
-            );
+        for (name, value) in self.entries() {
+            let value = match value {
+                OptionValue::Bool(value) => JsValue::from_bool(value),
+                OptionValue::Number(value) => JsValue::from_f64(value),
+            };
+            let _ = js_sys::Reflect::set(&obj, &JsValue::from_str(name), &value);
         }
 
-        if let Some(overflow) = self.maximum_cache_overflow_bytes {
-            let _ = js_sys::Reflect::set(
-                &obj,
-                &JsValue::from_str("maximumCacheOverflowBytes"),
-                &JsValue::from_f64(overflow as f64),
-            );
-        }
+        obj.into()
+    }
+}
 
-        if let Some(collision) = self.enable_collision {
-            let _ = js_sys::Reflect::set(
-                &obj,
-                &JsValue::from_str("enableCollision"),
-                &JsValue::from_bool(collision),
-            );
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        if let Some(error) = self.maximum_screen_space_error {
-            let _ = js_sys::Reflect::set(
-                &obj,
-                &JsValue::from_str("maximumScreenSpaceError"),
-                &JsValue::from_f64(error),
-            );
-        }
+    #[test]
+    fn default_options_match_google_tiles() {
+        assert_eq!(
+            Cesium3DTilesetOptions::default().entries(),
+            [
+                ("cacheBytes", OptionValue::Number(1536.0 * 1024.0 * 1024.0)),
+                (
+                    "maximumCacheOverflowBytes",
+                    OptionValue::Number(1024.0 * 1024.0 * 1024.0)
+                ),
+                ("enableCollision", OptionValue::Bool(true)),
+            ]
+        );
+    }
 
-        obj.into()
+    #[test]
+    fn unset_options_are_left_to_cesium() {
+        let options = Cesium3DTilesetOptions {
+            cache_bytes: None,
+            maximum_cache_overflow_bytes: None,
+            enable_collision: Some(false),
+            maximum_screen_space_error: Some(8.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.entries(),
+            [
+                ("maximumScreenSpaceError", OptionValue::Number(8.0)),
+                ("enableCollision", OptionValue::Bool(false)),
+            ]
+        );
+    }
+
+    #[test]
+    fn level_of_detail_options_use_cesium_names() {
+        let options = Cesium3DTilesetOptions {
+            cache_bytes: None,
+            maximum_cache_overflow_bytes: None,
+            enable_collision: None,
+            shadows: Some(ShadowMode::ReceiveOnly),
+            skip_level_of_detail: Some(true),
+            skip_levels: Some(2),
+            dynamic_screen_space_error_factor: Some(12.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.entries(),
+            [
+                ("shadows", OptionValue::Number(3.0)),
+                ("dynamicScreenSpaceErrorFactor", OptionValue::Number(12.0)),
+                ("skipLevels", OptionValue::Number(2.0)),
+                ("skipLevelOfDetail", OptionValue::Bool(true)),
+            ]
+        );
     }
 }
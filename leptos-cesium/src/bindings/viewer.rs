@@ -3,9 +3,13 @@
 use wasm_bindgen::prelude::*;
 use web_sys::HtmlElement;
 
-use crate::bindings::JulianDate;
 use crate::bindings::data_source::DataSourceCollection;
 use crate::bindings::entity::{Entity, EntityCollection};
+use crate::bindings::imagery::ImageryLayerCollection;
+use crate::bindings::{
+    BoundingSphere, Cartesian2, Cartesian3, Cartographic, Fog, Globe, JulianDate, Matrix4, Moon,
+    Rectangle, ScreenSpaceCameraController, SkyAtmosphere, SkyBox, Sun,
+};
 
 #[wasm_bindgen]
 extern "C" {
@@ -18,12 +22,18 @@ extern "C" {
     #[wasm_bindgen(method, js_name = destroy)]
     pub fn destroy(this: &Viewer) -> bool;
 
+    #[wasm_bindgen(method, js_name = isDestroyed)]
+    pub fn is_destroyed(this: &Viewer) -> bool;
+
     #[wasm_bindgen(method, getter, js_name = entities)]
     pub fn entities(this: &Viewer) -> EntityCollection;
 
     #[wasm_bindgen(method, getter, js_name = dataSources)]
     pub fn data_sources(this: &Viewer) -> DataSourceCollection;
 
+    #[wasm_bindgen(method, getter, js_name = imageryLayers)]
+    pub fn imagery_layers(this: &Viewer) -> ImageryLayerCollection;
+
     #[wasm_bindgen(method, getter, js_name = camera)]
     pub fn camera(this: &Viewer) -> Camera;
 
@@ -33,9 +43,32 @@ extern "C" {
     #[wasm_bindgen(method, getter, js_name = scene)]
     pub fn scene(this: &Viewer) -> Scene;
 
+    /// The element the viewer was created in
+    #[wasm_bindgen(method, getter, js_name = container)]
+    pub fn container(this: &Viewer) -> web_sys::Element;
+
+    /// Scale of the rendering resolution relative to the canvas size in CSS pixels
+    #[wasm_bindgen(method, getter, js_name = resolutionScale)]
+    pub fn resolution_scale(this: &Viewer) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = resolutionScale)]
+    pub fn set_resolution_scale(this: &Viewer, value: f64);
+
     #[wasm_bindgen(method, js_name = zoomTo)]
     pub fn zoom_to(this: &Viewer, target: &JsValue) -> js_sys::Promise;
 
+    /// Flies the camera to the provided entity, entities, data source or tileset
+    #[wasm_bindgen(method, js_name = flyTo)]
+    pub fn fly_to(this: &Viewer, target: &JsValue) -> js_sys::Promise;
+
+    /// Flies to the target with `duration`, `maximumHeight` and `offset` options
+    #[wasm_bindgen(method, js_name = flyTo)]
+    pub fn fly_to_with_options(
+        this: &Viewer,
+        target: &JsValue,
+        options: &JsValue,
+    ) -> js_sys::Promise;
+
     #[wasm_bindgen(method, js_name = zoomTo)]
     pub fn zoom_to_with_offset(
         this: &Viewer,
@@ -85,9 +118,148 @@ extern "C" {
     #[wasm_bindgen(method, js_name = flyTo)]
     pub fn fly_to(this: &Camera, options: &JsValue);
 
+    /// Like `fly_to`, but returns Cesium's error for invalid options instead of throwing
+    #[wasm_bindgen(method, catch, js_name = flyTo)]
+    pub fn try_fly_to(this: &Camera, options: &JsValue) -> Result<(), JsValue>;
+
+    /// Stops the current flight where it is; its `cancel` callback runs
+    #[wasm_bindgen(method, js_name = cancelFlight)]
+    pub fn cancel_flight(this: &Camera);
+
+    /// Returns the position from which the camera sees the whole rectangle, in the current
+    /// scene mode's coordinates
+    #[wasm_bindgen(method, js_name = getRectangleCameraCoordinates)]
+    pub fn get_rectangle_camera_coordinates(this: &Camera, rectangle: &Rectangle) -> Cartesian3;
+
+    /// Flies the camera to a position where the whole sphere is visible
+    #[wasm_bindgen(method, catch, js_name = flyToBoundingSphere)]
+    pub fn fly_to_bounding_sphere(
+        this: &Camera,
+        sphere: &BoundingSphere,
+        options: &JsValue,
+    ) -> Result<(), JsValue>;
+
     #[wasm_bindgen(method, js_name = setView)]
     pub fn set_view(this: &Camera, options: &JsValue);
 
+    /// Returns where the ray through the window position hits the WGS84 ellipsoid, ignoring
+    /// terrain, or `None` when it misses the globe
+    #[wasm_bindgen(method, js_name = pickEllipsoid)]
+    pub fn pick_ellipsoid(this: &Camera, window_position: &Cartesian2) -> Option<Cartesian3>;
+
+    /// Camera position in the current reference frame
+    #[wasm_bindgen(method, getter, js_name = position)]
+    pub fn position(this: &Camera) -> Cartesian3;
+
+    /// Camera position in world coordinates
+    #[wasm_bindgen(method, getter, js_name = positionWC)]
+    pub fn position_wc(this: &Camera) -> Cartesian3;
+
+    /// Camera position as longitude, latitude and height
+    #[wasm_bindgen(method, getter, js_name = positionCartographic)]
+    pub fn position_cartographic(this: &Camera) -> Cartographic;
+
+    #[wasm_bindgen(method, getter, js_name = heading)]
+    pub fn heading(this: &Camera) -> f64;
+
+    #[wasm_bindgen(method, getter, js_name = pitch)]
+    pub fn pitch(this: &Camera) -> f64;
+
+    #[wasm_bindgen(method, getter, js_name = roll)]
+    pub fn roll(this: &Camera) -> f64;
+
+    /// Looks at `target` from `offset`, a `HeadingPitchRange` or a `Cartesian3` in the
+    /// target's east-north-up frame. The camera stays locked to the target until
+    /// `look_at_transform` is called with the identity matrix.
+    #[wasm_bindgen(method, js_name = lookAt)]
+    pub fn look_at(this: &Camera, target: &Cartesian3, offset: &JsValue);
+
+    /// Sets the camera's reference frame to `transform` and looks at its origin from `offset`
+    #[wasm_bindgen(method, js_name = lookAtTransform)]
+    pub fn look_at_transform(this: &Camera, transform: &Matrix4, offset: &JsValue);
+
+    /// Sets the camera's reference frame without moving it relative to the new origin
+    #[wasm_bindgen(method, js_name = lookAtTransform)]
+    pub fn set_transform(this: &Camera, transform: &Matrix4);
+
+    /// Rotates the camera around `axis` by `angle` radians, keeping its distance to the
+    /// reference frame's origin
+    #[wasm_bindgen(method, js_name = rotate)]
+    pub fn rotate(this: &Camera, axis: &Cartesian3, angle: f64);
+
+    #[wasm_bindgen(method, js_name = rotateLeft)]
+    pub fn rotate_left(this: &Camera, angle: f64);
+
+    #[wasm_bindgen(method, js_name = rotateRight)]
+    pub fn rotate_right(this: &Camera, angle: f64);
+
+    #[wasm_bindgen(method, js_name = rotateUp)]
+    pub fn rotate_up(this: &Camera, angle: f64);
+
+    #[wasm_bindgen(method, js_name = rotateDown)]
+    pub fn rotate_down(this: &Camera, angle: f64);
+
+    /// Zooms in along the view direction by `amount` meters
+    #[wasm_bindgen(method, js_name = zoomIn)]
+    pub fn zoom_in(this: &Camera, amount: f64);
+
+    /// Zooms out along the view direction by `amount` meters
+    #[wasm_bindgen(method, js_name = zoomOut)]
+    pub fn zoom_out(this: &Camera, amount: f64);
+
+    /// Moves the camera by `amount` meters along `direction`
+    #[wasm_bindgen(method, js_name = "move")]
+    pub fn move_(this: &Camera, direction: &Cartesian3, amount: f64);
+
+    #[wasm_bindgen(method, js_name = moveForward)]
+    pub fn move_forward(this: &Camera, amount: f64);
+
+    #[wasm_bindgen(method, js_name = moveBackward)]
+    pub fn move_backward(this: &Camera, amount: f64);
+
+    #[wasm_bindgen(method, js_name = moveUp)]
+    pub fn move_up(this: &Camera, amount: f64);
+
+    #[wasm_bindgen(method, js_name = moveDown)]
+    pub fn move_down(this: &Camera, amount: f64);
+
+    #[wasm_bindgen(method, js_name = moveLeft)]
+    pub fn move_left(this: &Camera, amount: f64);
+
+    #[wasm_bindgen(method, js_name = moveRight)]
+    pub fn move_right(this: &Camera, amount: f64);
+
+    /// Turns the camera in place by `angle` radians
+    #[wasm_bindgen(method, js_name = lookLeft)]
+    pub fn look_left(this: &Camera, angle: f64);
+
+    #[wasm_bindgen(method, js_name = lookRight)]
+    pub fn look_right(this: &Camera, angle: f64);
+
+    #[wasm_bindgen(method, js_name = lookUp)]
+    pub fn look_up(this: &Camera, angle: f64);
+
+    #[wasm_bindgen(method, js_name = lookDown)]
+    pub fn look_down(this: &Camera, angle: f64);
+
+    #[wasm_bindgen(method, js_name = twistLeft)]
+    pub fn twist_left(this: &Camera, angle: f64);
+
+    #[wasm_bindgen(method, js_name = twistRight)]
+    pub fn twist_right(this: &Camera, angle: f64);
+
+    /// Event fired when the camera starts moving
+    #[wasm_bindgen(method, getter, js_name = moveStart)]
+    pub fn move_start(this: &Camera) -> Event;
+
+    /// Event fired when the camera stops moving
+    #[wasm_bindgen(method, getter, js_name = moveEnd)]
+    pub fn move_end(this: &Camera) -> Event;
+
+    /// Event fired when the camera has changed by `percentageChanged`
+    #[wasm_bindgen(method, getter, js_name = changed)]
+    pub fn changed(this: &Camera) -> Event;
+
     /// Clock for controlling time and animation
     #[wasm_bindgen(js_namespace = Cesium, js_name = Clock)]
     pub type Clock;
@@ -104,6 +276,10 @@ extern "C" {
     #[wasm_bindgen(method, setter, js_name = currentTime)]
     pub fn set_current_time(this: &Clock, value: &JulianDate);
 
+    /// Event fired whenever the clock ticks, once per rendered frame
+    #[wasm_bindgen(method, getter, js_name = onTick)]
+    pub fn on_tick(this: &Clock) -> Event;
+
     /// Scene contains the primitives and other visual elements
     #[wasm_bindgen(js_namespace = Cesium, js_name = Scene)]
     pub type Scene;
@@ -111,6 +287,128 @@ extern "C" {
     #[wasm_bindgen(method, getter, js_name = primitives)]
     pub fn primitives(this: &Scene) -> PrimitiveCollection;
 
+    #[wasm_bindgen(method, getter, js_name = canvas)]
+    pub fn canvas(this: &Scene) -> web_sys::HtmlCanvasElement;
+
+    /// Returns the topmost object at the window position, or `undefined`
+    #[wasm_bindgen(method, js_name = pick)]
+    pub fn pick(this: &Scene, window_position: &Cartesian2) -> JsValue;
+
+    /// Returns every object at the window position, topmost first, up to `limit` objects
+    #[wasm_bindgen(method, js_name = drillPick)]
+    pub fn drill_pick(
+        this: &Scene,
+        window_position: &Cartesian2,
+        limit: Option<u32>,
+    ) -> js_sys::Array;
+
+    /// Returns the position on the rendered surface at the window position, read from the depth
+    /// buffer, so it lands on terrain, 3D Tiles and models
+    #[wasm_bindgen(method, catch, js_name = pickPosition)]
+    pub fn pick_position(
+        this: &Scene,
+        window_position: &Cartesian2,
+    ) -> Result<Option<Cartesian3>, JsValue>;
+
+    /// Whether `pick_position` is supported by the WebGL implementation
+    #[wasm_bindgen(method, getter, js_name = pickPositionSupported)]
+    pub fn pick_position_supported(this: &Scene) -> bool;
+
+    /// The globe, or `None` when the viewer was created without one
+    #[wasm_bindgen(method, getter, js_name = globe)]
+    pub fn globe(this: &Scene) -> Option<Globe>;
+
+    /// Turns user input into camera motion
+    #[wasm_bindgen(method, getter, js_name = screenSpaceCameraController)]
+    pub fn screen_space_camera_controller(this: &Scene) -> ScreenSpaceCameraController;
+
+    /// Event fired before each frame is rendered
+    #[wasm_bindgen(method, getter, js_name = preRender)]
+    pub fn pre_render(this: &Scene) -> Event;
+
+    /// Event fired right after each frame is rendered, while the drawing buffer is intact
+    #[wasm_bindgen(method, getter, js_name = postRender)]
+    pub fn post_render(this: &Scene) -> Event;
+
+    /// Asks for a new frame; needed after changing the scene in request render mode
+    #[wasm_bindgen(method, js_name = requestRender)]
+    pub fn request_render(this: &Scene);
+
+    /// Render only when requested or when the scene changes on its own
+    #[wasm_bindgen(method, getter, js_name = requestRenderMode)]
+    pub fn request_render_mode(this: &Scene) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = requestRenderMode)]
+    pub fn set_request_render_mode(this: &Scene, value: bool);
+
+    /// Simulation seconds after which a frame is rendered in request render mode
+    #[wasm_bindgen(method, getter, js_name = maximumRenderTimeChange)]
+    pub fn maximum_render_time_change(this: &Scene) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = maximumRenderTimeChange)]
+    pub fn set_maximum_render_time_change(this: &Scene, value: f64);
+
+    /// Current `Cesium.SceneMode` value, see `SceneMode::from_cesium`
+    #[wasm_bindgen(method, getter, js_name = mode)]
+    pub fn mode(this: &Scene) -> u32;
+
+    /// Morphs to a 2D map over `duration` seconds
+    #[wasm_bindgen(method, js_name = morphTo2D)]
+    pub fn morph_to_2d(this: &Scene, duration: f64);
+
+    /// Morphs to a 3D globe over `duration` seconds
+    #[wasm_bindgen(method, js_name = morphTo3D)]
+    pub fn morph_to_3d(this: &Scene, duration: f64);
+
+    /// Morphs to Columbus view over `duration` seconds
+    #[wasm_bindgen(method, js_name = morphToColumbusView)]
+    pub fn morph_to_columbus_view(this: &Scene, duration: f64);
+
+    /// Ends a running morph immediately, jumping to the target mode
+    #[wasm_bindgen(method, js_name = completeMorph)]
+    pub fn complete_morph(this: &Scene);
+
+    /// Event fired when a morph between scene modes completes
+    #[wasm_bindgen(method, getter, js_name = morphComplete)]
+    pub fn morph_complete(this: &Scene) -> Event;
+
+    #[wasm_bindgen(method, getter, js_name = fog)]
+    pub fn fog(this: &Scene) -> Fog;
+
+    /// The star background, or `None` when the viewer was created without one
+    #[wasm_bindgen(method, getter, js_name = skyBox)]
+    pub fn sky_box(this: &Scene) -> Option<SkyBox>;
+
+    #[wasm_bindgen(method, getter, js_name = skyAtmosphere)]
+    pub fn sky_atmosphere(this: &Scene) -> Option<SkyAtmosphere>;
+
+    #[wasm_bindgen(method, getter, js_name = sun)]
+    pub fn sun(this: &Scene) -> Option<Sun>;
+
+    #[wasm_bindgen(method, getter, js_name = moon)]
+    pub fn moon(this: &Scene) -> Option<Moon>;
+
+    /// Whether high dynamic range rendering is on; ignored where the GPU doesn't support it
+    #[wasm_bindgen(method, getter, js_name = highDynamicRange)]
+    pub fn high_dynamic_range(this: &Scene) -> bool;
+
+    #[wasm_bindgen(method, setter, js_name = highDynamicRange)]
+    pub fn set_high_dynamic_range(this: &Scene, value: bool);
+
+    /// Scalar exaggerating terrain and 3D Tiles heights
+    #[wasm_bindgen(method, getter, js_name = verticalExaggeration)]
+    pub fn vertical_exaggeration(this: &Scene) -> f64;
+
+    #[wasm_bindgen(method, setter, js_name = verticalExaggeration)]
+    pub fn set_vertical_exaggeration(this: &Scene, value: f64);
+
+    /// The terrain provider the globe is drawn with
+    #[wasm_bindgen(method, getter, js_name = terrainProvider)]
+    pub fn terrain_provider(this: &Scene) -> JsValue;
+
+    #[wasm_bindgen(method, setter, js_name = terrainProvider)]
+    pub fn set_terrain_provider(this: &Scene, provider: &JsValue);
+
     /// Collection of primitives in the scene
     #[wasm_bindgen(js_namespace = Cesium, js_name = PrimitiveCollection)]
     pub type PrimitiveCollection;
@@ -132,6 +430,40 @@ impl Viewer {
     }
 }
 
+#[cfg(target_arch = "wasm32")]
+impl Camera {
+    /// Releases a camera locked by `look_at`, returning it to the world reference frame
+    pub fn release_look_at(&self) {
+        self.set_transform(&Matrix4::identity());
+    }
+}
+
+/// A listener added to a Cesium `Event`, removed from it and freed when dropped
+///
+/// Keep it in a stored value and take it out on cleanup to detach the listener.
+#[cfg(target_arch = "wasm32")]
+pub struct EventListener<T: ?Sized + wasm_bindgen::closure::WasmClosure> {
+    event: Event,
+    closure: Closure<T>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized + wasm_bindgen::closure::WasmClosure> EventListener<T> {
+    /// Add `closure` as a listener of `event`
+    pub fn new(event: Event, closure: Closure<T>) -> Self {
+        event.add_event_listener(closure.as_ref().unchecked_ref());
+        Self { event, closure }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized + wasm_bindgen::closure::WasmClosure> Drop for EventListener<T> {
+    fn drop(&mut self) {
+        self.event
+            .remove_event_listener(self.closure.as_ref().unchecked_ref());
+    }
+}
+
 // Helper function to get current JulianDate using reflection
 #[cfg(target_arch = "wasm32")]
 pub fn julian_date_now() -> JulianDate {
@@ -2,20 +2,27 @@
 
 use leptos::prelude::*;
 
+use crate::bindings::Cesium3DTileset;
 use crate::core::JsSignal;
 
 #[cfg(target_arch = "wasm32")]
 use crate::bindings::{
-    BoundingSphere, Cartesian3, FlyToOptions, HeadingPitchRange, HeadingPitchRoll, SetViewOptions,
-    Viewer, julian_date_now,
+    BoundingSphere, Camera, Cartesian3, DataSource, EasingFunction, EventListener, FlightOutcome,
+    FlyToOptions, HeadingPitchRange, HeadingPitchRoll, ScreenSpaceEventHandler,
+    ScreenSpaceEventType, SetViewOptions, Viewer, fly_home, fly_to, fly_to_bounding_sphere,
+    julian_date_now,
 };
 #[cfg(target_arch = "wasm32")]
 use crate::components::use_cesium_context;
 #[cfg(target_arch = "wasm32")]
-use wasm_bindgen::{JsCast, JsValue};
+use crate::core::{CesiumError, JsStoredValue};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
 
 #[cfg(not(target_arch = "wasm32"))]
-use crate::bindings::{BoundingSphere, Cartesian3, HeadingPitchRange, HeadingPitchRoll};
+use crate::bindings::{
+    BoundingSphere, Cartesian3, EasingFunction, HeadingPitchRange, HeadingPitchRoll,
+};
 
 /// Camera fly home component that triggers camera to return to home view
 ///
@@ -39,11 +46,20 @@ pub fn CameraFlyHome(
     /// Duration of flight in seconds (default: 0.0 = instant)
     #[prop(optional, into)]
     duration: Signal<f64>,
+    /// Called when the camera arrives home
+    #[prop(optional, into)]
+    on_complete: Option<UnsyncCallback<()>>,
+    /// Called when the flight is interrupted by user input or another flight
+    #[prop(optional, into)]
+    on_cancel: Option<UnsyncCallback<()>>,
 ) -> impl IntoView {
     #[cfg(target_arch = "wasm32")]
     {
         let viewer_context =
             use_cesium_context().expect("CameraFlyHome must be inside ViewerContainer");
+        // Cleared on unmount, so flights still running then report nothing
+        let mounted = StoredValue::new(true);
+        on_cleanup(move || mounted.set_value(false));
 
         Effect::new(move |_| {
             // Track the trigger signal
@@ -52,14 +68,20 @@ pub fn CameraFlyHome(
             let dur = duration.get();
 
             viewer_context.with_viewer(|viewer: Viewer| {
-                viewer.camera().fly_home(dur);
+                report_flight(
+                    "CameraFlyHome",
+                    async move { fly_home(&viewer, dur).await },
+                    mounted,
+                    on_complete,
+                    on_cancel,
+                );
             });
         });
     }
 
     #[cfg(not(target_arch = "wasm32"))]
     {
-        let _ = (trigger, duration);
+        let _ = (trigger, duration, on_complete, on_cancel);
     }
 }
 
@@ -104,6 +126,7 @@ pub fn CameraSetView(
 
                 viewer.camera().set_view(&options.build());
             });
+            viewer_context.request_render();
         });
     }
 
@@ -142,11 +165,32 @@ pub fn CameraFlyTo(
     /// Offset from destination (heading, pitch, range)
     #[prop(optional, into)]
     offset: JsSignal<Option<HeadingPitchRange>>,
+    /// Easing of the flight
+    #[prop(optional, into)]
+    easing_function: Signal<Option<EasingFunction>>,
+    /// Maximum height in meters at the peak of the flight
+    #[prop(optional, into)]
+    maximum_height: Signal<Option<f64>>,
+    /// Look down at the destination while flying above this height in meters
+    #[prop(optional, into)]
+    pitch_adjust_height: Signal<Option<f64>>,
+    /// Always fly over this longitude in radians
+    #[prop(optional, into)]
+    fly_over_longitude: Signal<Option<f64>>,
+    /// Called when the camera reaches the destination
+    #[prop(optional, into)]
+    on_complete: Option<UnsyncCallback<()>>,
+    /// Called when the flight is interrupted by user input or another flight
+    #[prop(optional, into)]
+    on_cancel: Option<UnsyncCallback<()>>,
 ) -> impl IntoView {
     #[cfg(target_arch = "wasm32")]
     {
         let viewer_context =
             use_cesium_context().expect("CameraFlyTo must be inside ViewerContainer");
+        // Cleared on unmount, so flights still running then report nothing
+        let mounted = StoredValue::new(true);
+        on_cleanup(move || mounted.set_value(false));
 
         Effect::new(move |_| {
             let dest = destination.get_untracked();
@@ -165,14 +209,47 @@ pub fn CameraFlyTo(
                     options = options.offset(offset_val);
                 }
 
-                viewer.camera().fly_to(&options.build());
+                if let Some(easing) = easing_function.get_untracked() {
+                    options = options.easing_function(easing);
+                }
+
+                if let Some(height) = maximum_height.get_untracked() {
+                    options = options.maximum_height(height);
+                }
+
+                if let Some(height) = pitch_adjust_height.get_untracked() {
+                    options = options.pitch_adjust_height(height);
+                }
+
+                if let Some(longitude) = fly_over_longitude.get_untracked() {
+                    options = options.fly_over_longitude(longitude);
+                }
+
+                report_flight(
+                    "CameraFlyTo",
+                    async move { fly_to(&viewer, options).await },
+                    mounted,
+                    on_complete,
+                    on_cancel,
+                );
             });
         });
     }
 
     #[cfg(not(target_arch = "wasm32"))]
     {
-        let _ = (destination, orientation, duration, offset);
+        let _ = (
+            destination,
+            orientation,
+            duration,
+            offset,
+            easing_function,
+            maximum_height,
+            pitch_adjust_height,
+            fly_over_longitude,
+            on_complete,
+            on_cancel,
+        );
     }
 }
 
@@ -205,11 +282,20 @@ pub fn CameraFlyToBoundingSphere(
     /// Duration of flight in seconds (default: 3.0)
     #[prop(optional, into, default = 3.0.into())]
     duration: Signal<f64>,
+    /// Called when the camera reaches the sphere
+    #[prop(optional, into)]
+    on_complete: Option<UnsyncCallback<()>>,
+    /// Called when the flight is interrupted by user input or another flight
+    #[prop(optional, into)]
+    on_cancel: Option<UnsyncCallback<()>>,
 ) -> impl IntoView {
     #[cfg(target_arch = "wasm32")]
     {
         let viewer_context =
             use_cesium_context().expect("CameraFlyToBoundingSphere must be inside ViewerContainer");
+        // Cleared on unmount, so flights still running then report nothing
+        let mounted = StoredValue::new(true);
+        on_cleanup(move || mounted.set_value(false));
 
         Effect::new(move |_| {
             let sphere = target.get_untracked();
@@ -217,47 +303,441 @@ pub fn CameraFlyToBoundingSphere(
             let dur = duration.get();
 
             viewer_context.with_viewer(|viewer: Viewer| {
-                use js_sys::{Object, Reflect};
+                report_flight(
+                    "CameraFlyToBoundingSphere",
+                    async move { fly_to_bounding_sphere(&viewer, &sphere, off, Some(dur)).await },
+                    mounted,
+                    on_complete,
+                    on_cancel,
+                );
+            });
+        });
+    }
 
-                let options = Object::new();
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (target, offset, duration, on_complete, on_cancel);
+    }
+}
 
-                // Set duration
-                let _ = Reflect::set(
-                    &options,
-                    &JsValue::from_str("duration"),
-                    &JsValue::from_f64(dur),
-                );
+/// Camera orbit component that slowly circles a point, e.g. for kiosk and showcase displays
+///
+/// The camera is locked onto `target` at `offset` and rotated by `speed` radians per second
+/// on every clock tick. Mouse, wheel or touch input on the globe stops the orbit, hands the
+/// camera back to the user and calls `on_interrupt`. The orbit restarts when `target` or
+/// `offset` change, or when `active` is switched back on.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// view! {
+///     <ViewerContainer>
+///         <CameraOrbit
+///             target=Cartesian3::from_degrees(2.2945, 48.8584, 150.0)
+///             offset=Some(HeadingPitchRange::new(0.0, -0.4, 1500.0))
+///             speed=0.05
+///         />
+///     </ViewerContainer>
+/// }
+/// ```
+#[component(transparent)]
+pub fn CameraOrbit(
+    /// Point to orbit around
+    #[prop(into)]
+    target: JsSignal<Cartesian3>,
+    /// Starting offset from the target (default: 30° down from 1 km away)
+    #[prop(optional, into)]
+    offset: JsSignal<Option<HeadingPitchRange>>,
+    /// Rotation speed in radians per second; negative values orbit the other way (default: 0.1)
+    #[prop(optional, into, default = 0.1.into())]
+    speed: Signal<f64>,
+    /// Whether the camera orbits (default: true)
+    #[prop(optional, into, default = true.into())]
+    active: Signal<bool>,
+    /// Called when user input stops the orbit
+    #[prop(optional, into)]
+    on_interrupt: Option<UnsyncCallback<()>>,
+) -> impl IntoView {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context =
+            use_cesium_context().expect("CameraOrbit must be inside ViewerContainer");
+        let orbit: JsStoredValue<Option<Orbit>> = StoredValue::new_local(None);
+        let handler: JsStoredValue<Option<InterruptHandler>> = StoredValue::new_local(None);
 
-                // Set offset if provided
-                if let Some(offset_val) = off {
+        Effect::new(move |_| {
+            let center = target.get();
+            let offset = offset.get().unwrap_or_else(|| {
+                HeadingPitchRange::new(0.0, -std::f64::consts::FRAC_PI_6, 1000.0)
+            });
+            let active = active.get();
+            let Some(viewer) = viewer_context.viewer() else {
+                return;
+            };
+
+            stop_orbit(orbit);
+            destroy_interrupt_handler(handler);
+            if !active {
+                return;
+            }
+
+            let camera = viewer.camera();
+            camera.look_at(&center, &offset);
+
+            // Rotate by wall-clock time so the clock multiplier doesn't change the speed
+            let mut last_tick = js_sys::Date::now();
+            let rotated = viewer.camera();
+            let tick = Closure::wrap(Box::new(move |_clock: JsValue| {
+                let now = js_sys::Date::now();
+                let elapsed = (now - last_tick) / 1000.0;
+                last_tick = now;
+                rotated.rotate_right(speed.try_get_untracked().unwrap_or_default() * elapsed);
+            }) as Box<dyn FnMut(JsValue)>);
+            orbit.set_value(Some(Orbit {
+                camera,
+                _tick: EventListener::new(viewer.clock().on_tick(), tick),
+            }));
+
+            // The handler outlives an interruption so it isn't destroyed from its own callback
+            let screen_space_handler = ScreenSpaceEventHandler::new(&viewer.scene().canvas());
+            let interrupt = Closure::wrap(Box::new(move |_event: JsValue| {
+                if stop_orbit(orbit)
+                    && let Some(on_interrupt) = on_interrupt
+                {
+                    on_interrupt.run(());
+                }
+            }) as Box<dyn FnMut(JsValue)>);
+            for kind in USER_CAMERA_INPUTS {
+                screen_space_handler.set_input_action(interrupt.as_ref().unchecked_ref(), kind);
+            }
+            handler.set_value(Some((screen_space_handler, interrupt)));
+        });
+
+        on_cleanup(move || {
+            stop_orbit(orbit);
+            destroy_interrupt_handler(handler);
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (target, offset, speed, active, on_interrupt);
+    }
+}
+
+/// Run a flight and, while the component is `mounted`, the callback matching its outcome
+#[cfg(target_arch = "wasm32")]
+fn report_flight(
+    component: &'static str,
+    flight: impl Future<Output = Result<FlightOutcome, CesiumError>> + 'static,
+    mounted: StoredValue<bool>,
+    on_complete: Option<UnsyncCallback<()>>,
+    on_cancel: Option<UnsyncCallback<()>>,
+) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let outcome = flight.await;
+        if mounted.try_get_value() != Some(true) {
+            return;
+        }
+        let callback = match outcome {
+            Ok(FlightOutcome::Completed) => on_complete,
+            Ok(FlightOutcome::Cancelled) => on_cancel,
+            // The viewer was torn down before the flight started
+            Err(CesiumError::ViewerUnavailable) => None,
+            Err(error) => {
+                web_sys::console::error_1(&JsValue::from_str(&format!(
+                    "{component}: flight failed: {error}"
+                )));
+                None
+            }
+        };
+        if let Some(callback) = callback {
+            callback.try_run(());
+        }
+    });
+}
+
+/// Inputs with which the user takes over the camera
+#[cfg(target_arch = "wasm32")]
+pub(crate) const USER_CAMERA_INPUTS: [ScreenSpaceEventType; 5] = [
+    ScreenSpaceEventType::LeftDown,
+    ScreenSpaceEventType::RightDown,
+    ScreenSpaceEventType::MiddleDown,
+    ScreenSpaceEventType::Wheel,
+    ScreenSpaceEventType::PinchStart,
+];
+
+/// A running orbit: the tick listener and the camera it locked onto the target
+#[cfg(target_arch = "wasm32")]
+struct Orbit {
+    camera: Camera,
+    _tick: EventListener<dyn FnMut(JsValue)>,
+}
+
+/// Handler stopping a camera animation on user input, with the closure its input actions call
+#[cfg(target_arch = "wasm32")]
+pub(crate) type InterruptHandler = (ScreenSpaceEventHandler, Closure<dyn FnMut(JsValue)>);
+
+/// Stop a running orbit and release the camera; returns whether one was running
+#[cfg(target_arch = "wasm32")]
+fn stop_orbit(orbit: JsStoredValue<Option<Orbit>>) -> bool {
+    // Dropping the orbit removes its tick listener
+    let Some(orbit) = orbit.try_update_value(Option::take).flatten() else {
+        return false;
+    };
+    orbit.camera.release_look_at();
+    true
+}
+
+/// Destroy the interrupt handler, then free the closure it called
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn destroy_interrupt_handler(handler: JsStoredValue<Option<InterruptHandler>>) {
+    if let Some((handler, _interrupt)) = handler.try_update_value(Option::take).flatten() {
+        handler.dispose();
+    }
+}
+
+/// Take the handler out of its stored value and destroy it
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn destroy_handler(handler: JsStoredValue<Option<ScreenSpaceEventHandler>>) {
+    if let Some(handler) = handler.try_update_value(Option::take).flatten() {
+        handler.dispose();
+    }
+}
+
+/// What `ZoomTo` brings into view
+#[derive(Clone)]
+pub enum ZoomTarget {
+    /// The entity with this id, in the viewer or any data source
+    Entity(String),
+    /// All entities with these ids, in the viewer or any data source
+    Entities(Vec<String>),
+    /// The first data source with this name, e.g. the file name of a loaded GeoJSON
+    DataSource(String),
+    /// A loaded 3D tileset
+    Tileset(Cesium3DTileset),
+}
+
+#[cfg(target_arch = "wasm32")]
+impl ZoomTarget {
+    /// The Cesium object to zoom to, or `None` while any part of the target is missing
+    fn resolve(&self, viewer: &Viewer) -> Option<JsValue> {
+        match self {
+            Self::Entity(id) => find_entity(viewer, id).map(JsValue::from),
+            Self::Entities(ids) => ids
+                .iter()
+                .map(|id| find_entity(viewer, id).map(JsValue::from))
+                .collect::<Option<js_sys::Array>>()
+                .map(JsValue::from),
+            Self::DataSource(name) => viewer
+                .data_sources()
+                .get_by_name(name)
+                .into_iter()
+                .next()
+                .map(JsValue::from),
+            Self::Tileset(tileset) => Some(JsValue::from(tileset.clone())),
+        }
+    }
+}
+
+/// Find an entity by id in the viewer's own entities, then in each data source
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn find_entity(viewer: &Viewer, id: &str) -> Option<crate::bindings::Entity> {
+    viewer.entities().get_by_id(id).or_else(|| {
+        let data_sources = viewer.data_sources();
+        (0..data_sources.length())
+            .find_map(|index| data_sources.get(index).entities().get_by_id(id))
+    })
+}
+
+/// Zoom component that brings entities, data sources or tilesets into view
+///
+/// Uses `viewer.flyTo` (or `viewer.zoomTo` when `fly` is false), so Cesium computes the
+/// bounding sphere and waits for data sources that are still loading. A target that isn't
+/// in the viewer yet is zoomed to as soon as it is added, which makes fitting a GeoJSON file
+/// into view after it loads a one-liner. Zooms again whenever `target` or `trigger` change.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// view! {
+///     <ViewerContainer>
+///         <GeoJsonDataSource url="data/countries.geojson" />
+///         <ZoomTo
+///             target=ZoomTarget::DataSource("countries.geojson".into())
+///             offset=Some(HeadingPitchRange::new(0.0, -0.8, 0.0))
+///             duration=Some(1.5)
+///         />
+///     </ViewerContainer>
+/// }
+/// ```
+#[component(transparent)]
+pub fn ZoomTo(
+    /// What to bring into view
+    #[prop(into)]
+    target: JsSignal<ZoomTarget>,
+    /// Offset from the target (heading, pitch, range); a range of 0 fits the target
+    #[prop(optional, into)]
+    offset: JsSignal<Option<HeadingPitchRange>>,
+    /// Animate the camera instead of jumping (default: true)
+    #[prop(optional, into, default = true.into())]
+    fly: Signal<bool>,
+    /// Duration of flight in seconds (default: computed by Cesium from the distance)
+    #[prop(optional, into)]
+    duration: Signal<Option<f64>>,
+    /// Trigger signal - zooms again whenever this signal updates
+    #[prop(optional, into)]
+    trigger: Option<Signal<()>>,
+    /// Called when the target is in view
+    #[prop(optional, into)]
+    on_complete: Option<UnsyncCallback<()>>,
+    /// Called when the zoom is interrupted or the target has nothing to show
+    #[prop(optional, into)]
+    on_cancel: Option<UnsyncCallback<()>>,
+) -> impl IntoView {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context = use_cesium_context().expect("ZoomTo must be inside ViewerContainer");
+        // Listeners waiting for a missing target to be added
+        let waiting: JsStoredValue<Vec<AdditionListener>> = StoredValue::new_local(Vec::new());
+
+        let zoom = move |viewer: &Viewer, resolved: &JsValue| {
+            use js_sys::{Object, Reflect};
+
+            let offset = offset.get_untracked();
+            let promise = if fly.get_untracked() {
+                let options = Object::new();
+                if let Some(duration) = duration.get_untracked() {
                     let _ = Reflect::set(
                         &options,
-                        &JsValue::from_str("offset"),
-                        &JsValue::from(offset_val),
+                        &JsValue::from_str("duration"),
+                        &JsValue::from_f64(duration),
                     );
                 }
-
-                // Call camera.flyToBoundingSphere(sphere, options)
-                use js_sys::{Function, Reflect as JsReflect};
-                let camera = viewer.camera();
-                let fly_to_bs_fn =
-                    JsReflect::get(&camera, &JsValue::from_str("flyToBoundingSphere"))
-                        .expect("Camera.flyToBoundingSphere to exist");
-                let fly_to_bs_fn: Function = fly_to_bs_fn
-                    .dyn_into()
-                    .expect("Camera.flyToBoundingSphere to be callable");
-
-                let _ = fly_to_bs_fn.call2(&camera, &JsValue::from(sphere), &options.into());
+                if let Some(offset) = offset {
+                    let _ = Reflect::set(&options, &JsValue::from_str("offset"), &offset);
+                }
+                viewer.fly_to_with_options(resolved, &options)
+            } else {
+                match offset {
+                    Some(offset) => viewer.zoom_to_with_offset(resolved, &offset),
+                    None => viewer.zoom_to(resolved),
+                }
+            };
+            // The viewer resolves the target in its render loop
+            viewer.scene().request_render();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let callback = match wasm_bindgen_futures::JsFuture::from(promise).await {
+                    Ok(completed) if completed.is_truthy() => on_complete,
+                    Ok(_) => on_cancel,
+                    Err(error) => {
+                        web_sys::console::error_1(&JsValue::from_str(&format!(
+                            "ZoomTo: zoom failed: {}",
+                            CesiumError::from_js(&error)
+                        )));
+                        None
+                    }
+                };
+                if let Some(callback) = callback {
+                    callback.try_run(());
+                }
             });
+        };
+
+        Effect::new(move |_| {
+            if let Some(trigger) = trigger {
+                trigger.track();
+            }
+            let target = target.get();
+            let Some(viewer) = viewer_context.viewer() else {
+                return;
+            };
+
+            stop_waiting(waiting);
+            if let Some(resolved) = target.resolve(&viewer) {
+                zoom(&viewer, &resolved);
+                return;
+            }
+
+            let retry = move || {
+                let Some(viewer) = viewer_context.viewer_untracked() else {
+                    return;
+                };
+                if let Some(resolved) = target.resolve(&viewer) {
+                    stop_waiting(waiting);
+                    zoom(&viewer, &resolved);
+                }
+            };
+            watch_additions(&viewer, waiting, retry);
         });
+
+        on_cleanup(move || stop_waiting(waiting));
     }
 
     #[cfg(not(target_arch = "wasm32"))]
     {
-        let _ = (target, offset, duration);
+        let _ = (
+            target,
+            offset,
+            fly,
+            duration,
+            trigger,
+            on_complete,
+            on_cancel,
+        );
     }
 }
 
+/// Listener of an event reporting added entities or data sources
+#[cfg(target_arch = "wasm32")]
+type AdditionListener = EventListener<dyn FnMut(JsValue, JsValue)>;
+
+/// Call `retry` whenever entities are added to the viewer or to any of its data sources, or a
+/// data source is added, until `stop_waiting` removes the listeners
+#[cfg(target_arch = "wasm32")]
+fn watch_additions(
+    viewer: &Viewer,
+    waiting: JsStoredValue<Vec<AdditionListener>>,
+    retry: impl Fn() + Clone + 'static,
+) {
+    let on_entities_changed = {
+        let retry = retry.clone();
+        move |entities: crate::bindings::EntityCollection| {
+            let retry = retry.clone();
+            EventListener::new(
+                entities.collection_changed(),
+                Closure::wrap(Box::new(move |_: JsValue, _: JsValue| retry())
+                    as Box<dyn FnMut(JsValue, JsValue)>),
+            )
+        }
+    };
+
+    let data_sources = viewer.data_sources();
+    let mut listeners = vec![on_entities_changed(viewer.entities())];
+    listeners.extend(
+        (0..data_sources.length())
+            .map(|index| on_entities_changed(data_sources.get(index).entities())),
+    );
+    // Data sources may be added empty and filled later, so watch each new one's entities too
+    listeners.push(EventListener::new(
+        data_sources.data_source_added(),
+        Closure::wrap(Box::new(move |_collection: JsValue, data_source: JsValue| {
+            let listener =
+                on_entities_changed(data_source.unchecked_into::<DataSource>().entities());
+            waiting.try_update_value(|waiting| waiting.push(listener));
+            retry();
+        }) as Box<dyn FnMut(JsValue, JsValue)>),
+    ));
+    waiting.set_value(listeners);
+}
+
+/// Remove the listeners waiting for a missing target
+#[cfg(target_arch = "wasm32")]
+fn stop_waiting(waiting: JsStoredValue<Vec<AdditionListener>>) {
+    waiting.try_update_value(Vec::clear);
+}
+
 /// Clock reset component to reset viewer clock to current time and stop animation
 ///
 /// # Example
@@ -295,6 +775,7 @@ pub fn ClockReset(
                 // Stop animation
                 clock.set_should_animate(false);
             });
+            viewer_context.request_render();
         });
     }
 
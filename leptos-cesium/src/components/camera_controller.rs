@@ -0,0 +1,270 @@
+//! Camera controller component constraining how users can move the camera
+
+use leptos::prelude::*;
+
+use crate::bindings::{CameraInput, Rectangle};
+use crate::core::JsSignal;
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{
+    Cartographic, EventListener, HeadingPitchRoll, ScreenSpaceCameraController, SetViewOptions,
+};
+#[cfg(target_arch = "wasm32")]
+use crate::components::use_cesium_context;
+#[cfg(target_arch = "wasm32")]
+use crate::core::JsStoredValue;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+
+/// Controller properties restored when `CameraController` unmounts
+#[cfg(target_arch = "wasm32")]
+const CONTROLLER_PROPERTIES: [&str; 14] = [
+    "enableInputs",
+    "enableRotate",
+    "enableTranslate",
+    "enableZoom",
+    "enableTilt",
+    "enableLook",
+    "enableCollisionDetection",
+    "minimumZoomDistance",
+    "maximumZoomDistance",
+    "rotateEventTypes",
+    "tiltEventTypes",
+    "zoomEventTypes",
+    "translateEventTypes",
+    "lookEventTypes",
+];
+
+/// Camera controller component configuring how user input moves the camera
+///
+/// Props left unset keep Cesium's defaults, and every setting is restored when the component
+/// unmounts. `bounds` keeps the camera above a rectangle by moving it back inside before each
+/// frame; the rectangle must not cross the antimeridian.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// view! {
+///     <ViewerContainer>
+///         <CameraController
+///             minimum_zoom_distance=Some(250.0)
+///             maximum_zoom_distance=Some(200_000.0)
+///             enable_look=Some(false)
+///             tilt_event_types=Some(vec![CameraEventType::RightDrag.into()])
+///             bounds=Some(Rectangle::from_degrees(5.9, 45.8, 10.5, 47.8))
+///         />
+///     </ViewerContainer>
+/// }
+/// ```
+#[component(transparent)]
+pub fn CameraController(
+    /// Master switch for all camera input
+    #[prop(optional, into)]
+    enable_inputs: Signal<Option<bool>>,
+    /// Allow rotating the camera around the globe
+    #[prop(optional, into)]
+    enable_rotate: Signal<Option<bool>>,
+    /// Allow panning in 2D and Columbus view
+    #[prop(optional, into)]
+    enable_translate: Signal<Option<bool>>,
+    /// Allow zooming in and out
+    #[prop(optional, into)]
+    enable_zoom: Signal<Option<bool>>,
+    /// Allow tilting the camera
+    #[prop(optional, into)]
+    enable_tilt: Signal<Option<bool>>,
+    /// Allow turning the camera in place
+    #[prop(optional, into)]
+    enable_look: Signal<Option<bool>>,
+    /// Keep the camera above terrain and 3D Tiles
+    #[prop(optional, into)]
+    enable_collision_detection: Signal<Option<bool>>,
+    /// Minimum distance in meters between the camera and the surface
+    #[prop(optional, into)]
+    minimum_zoom_distance: Signal<Option<f64>>,
+    /// Maximum distance in meters between the camera and the surface
+    #[prop(optional, into)]
+    maximum_zoom_distance: Signal<Option<f64>>,
+    /// Inputs that rotate the camera
+    #[prop(optional, into)]
+    rotate_event_types: Signal<Option<Vec<CameraInput>>>,
+    /// Inputs that tilt the camera
+    #[prop(optional, into)]
+    tilt_event_types: Signal<Option<Vec<CameraInput>>>,
+    /// Inputs that zoom the camera
+    #[prop(optional, into)]
+    zoom_event_types: Signal<Option<Vec<CameraInput>>>,
+    /// Inputs that pan the camera
+    #[prop(optional, into)]
+    translate_event_types: Signal<Option<Vec<CameraInput>>>,
+    /// Inputs that turn the camera in place
+    #[prop(optional, into)]
+    look_event_types: Signal<Option<Vec<CameraInput>>>,
+    /// Area the camera position is kept inside
+    #[prop(optional, into)]
+    bounds: JsSignal<Option<Rectangle>>,
+) -> impl IntoView {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context =
+            use_cesium_context().expect("CameraController must be inside ViewerContainer");
+        // Cesium's settings before this component changed them
+        let original: JsStoredValue<Option<(ScreenSpaceCameraController, Vec<JsValue>)>> =
+            StoredValue::new_local(None);
+        let bounds_listener: JsStoredValue<Option<EventListener<dyn FnMut()>>> =
+            StoredValue::new_local(None);
+
+        let controller = move || -> Option<ScreenSpaceCameraController> {
+            let controller = viewer_context
+                .viewer()?
+                .scene()
+                .screen_space_camera_controller();
+            if original.with_value(Option::is_none) {
+                let values = CONTROLLER_PROPERTIES
+                    .iter()
+                    .map(|key| {
+                        js_sys::Reflect::get(&controller, &JsValue::from_str(key))
+                            .unwrap_or(JsValue::UNDEFINED)
+                    })
+                    .collect();
+                let snapshot: &JsValue = &controller;
+                original.set_value(Some((snapshot.clone().unchecked_into(), values)));
+            }
+            Some(controller)
+        };
+
+        Effect::new(move |_| {
+            let enable_inputs = enable_inputs.get();
+            let enable_rotate = enable_rotate.get();
+            let enable_translate = enable_translate.get();
+            let enable_zoom = enable_zoom.get();
+            let enable_tilt = enable_tilt.get();
+            let enable_look = enable_look.get();
+            let enable_collision_detection = enable_collision_detection.get();
+            let minimum_zoom_distance = minimum_zoom_distance.get();
+            let maximum_zoom_distance = maximum_zoom_distance.get();
+            let Some(controller) = controller() else {
+                return;
+            };
+
+            if let Some(value) = enable_inputs {
+                controller.set_enable_inputs(value);
+            }
+            if let Some(value) = enable_rotate {
+                controller.set_enable_rotate(value);
+            }
+            if let Some(value) = enable_translate {
+                controller.set_enable_translate(value);
+            }
+            if let Some(value) = enable_zoom {
+                controller.set_enable_zoom(value);
+            }
+            if let Some(value) = enable_tilt {
+                controller.set_enable_tilt(value);
+            }
+            if let Some(value) = enable_look {
+                controller.set_enable_look(value);
+            }
+            if let Some(value) = enable_collision_detection {
+                controller.set_enable_collision_detection(value);
+            }
+            if let Some(value) = minimum_zoom_distance {
+                controller.set_minimum_zoom_distance(value);
+            }
+            if let Some(value) = maximum_zoom_distance {
+                controller.set_maximum_zoom_distance(value);
+            }
+        });
+
+        Effect::new(move |_| {
+            let rotate = rotate_event_types.get();
+            let tilt = tilt_event_types.get();
+            let zoom = zoom_event_types.get();
+            let translate = translate_event_types.get();
+            let look = look_event_types.get();
+            let Some(controller) = controller() else {
+                return;
+            };
+
+            if let Some(inputs) = rotate {
+                controller.set_rotate_event_types(&inputs);
+            }
+            if let Some(inputs) = tilt {
+                controller.set_tilt_event_types(&inputs);
+            }
+            if let Some(inputs) = zoom {
+                controller.set_zoom_event_types(&inputs);
+            }
+            if let Some(inputs) = translate {
+                controller.set_translate_event_types(&inputs);
+            }
+            if let Some(inputs) = look {
+                controller.set_look_event_types(&inputs);
+            }
+        });
+
+        // Move the camera back inside the bounds before each frame
+        Effect::new(move |_| {
+            let bounds = bounds.get();
+            let viewer = viewer_context.viewer();
+            bounds_listener.set_value(None);
+            let (Some(bounds), Some(viewer)) = (bounds, viewer) else {
+                return;
+            };
+
+            let camera = viewer.camera();
+            let keep_inside = Closure::wrap(Box::new(move || {
+                let position = camera.position_cartographic();
+                let longitude = position.longitude().clamp(bounds.west(), bounds.east());
+                let latitude = position.latitude().clamp(bounds.south(), bounds.north());
+                if (longitude, latitude) == (position.longitude(), position.latitude()) {
+                    return;
+                }
+
+                let destination =
+                    Cartographic::new(longitude, latitude, position.height()).to_cartesian();
+                let orientation =
+                    HeadingPitchRoll::new(camera.heading(), camera.pitch(), camera.roll());
+                camera.set_view(
+                    &SetViewOptions::new(destination)
+                        .orientation(orientation)
+                        .build(),
+                );
+            }) as Box<dyn FnMut()>);
+            bounds_listener.set_value(Some(EventListener::new(
+                viewer.scene().pre_render(),
+                keep_inside,
+            )));
+        });
+
+        on_cleanup(move || {
+            bounds_listener.try_update_value(Option::take);
+            if let Some((controller, values)) = original.try_update_value(Option::take).flatten() {
+                for (key, value) in CONTROLLER_PROPERTIES.iter().zip(values) {
+                    let _ = js_sys::Reflect::set(&controller, &JsValue::from_str(key), &value);
+                }
+            }
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (
+            enable_inputs,
+            enable_rotate,
+            enable_translate,
+            enable_zoom,
+            enable_tilt,
+            enable_look,
+            enable_collision_detection,
+            minimum_zoom_distance,
+            maximum_zoom_distance,
+            rotate_event_types,
+            tilt_event_types,
+            zoom_event_types,
+            translate_event_types,
+            look_event_types,
+            bounds,
+        );
+    }
+}
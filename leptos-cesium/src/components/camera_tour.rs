@@ -0,0 +1,259 @@
+//! Guided camera tours over a sequence of stops
+
+use leptos::prelude::*;
+
+use crate::bindings::{CameraView, EasingFunction};
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{FlightOutcome, ScreenSpaceEventHandler, fly_to};
+#[cfg(target_arch = "wasm32")]
+use crate::components::camera::{
+    InterruptHandler, USER_CAMERA_INPUTS, destroy_interrupt_handler, find_entity,
+};
+#[cfg(target_arch = "wasm32")]
+use crate::components::use_cesium_context;
+#[cfg(target_arch = "wasm32")]
+use crate::core::JsStoredValue;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+
+/// A stop of a `CameraTour`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TourStop {
+    /// Where the camera goes and which way it looks
+    pub view: CameraView,
+    /// Flight duration in seconds (default: computed by Cesium from the distance)
+    pub duration: Option<f64>,
+    /// Seconds to stay at the stop before flying on
+    pub dwell: f64,
+    /// Easing of the flight to this stop
+    pub easing: Option<EasingFunction>,
+    /// Id of an entity to select on arrival
+    pub select: Option<String>,
+}
+
+impl TourStop {
+    /// Stop at a view, dwelling for 3 seconds
+    pub fn new(view: CameraView) -> Self {
+        Self {
+            view,
+            duration: None,
+            dwell: 3.0,
+            easing: None,
+            select: None,
+        }
+    }
+
+    /// Set the flight duration in seconds
+    pub fn duration(mut self, seconds: f64) -> Self {
+        self.duration = Some(seconds);
+        self
+    }
+
+    /// Set how many seconds to stay at the stop
+    pub fn dwell(mut self, seconds: f64) -> Self {
+        self.dwell = seconds;
+        self
+    }
+
+    /// Set the easing of the flight to this stop
+    pub fn easing(mut self, easing: EasingFunction) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    /// Select the entity with this id on arrival
+    pub fn select(mut self, entity_id: impl Into<String>) -> Self {
+        self.select = Some(entity_id.into());
+        self
+    }
+}
+
+/// Camera tour component flying through a sequence of stops
+///
+/// The tour flies to the stop at `current`, dwells there, then advances. Set `playing` to
+/// pause and resume, write `current` to skip to a stop, and set `looping` to start over after
+/// the last stop instead of finishing. Mouse, wheel or touch input on the globe pauses the tour
+/// and calls `on_interrupt`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let playing = RwSignal::new(true);
+/// let current = RwSignal::new(0);
+/// let stops = vec![
+///     TourStop::new(CameraView::new(2.2945, 48.8584, 1200.0).orientation(0.0, -35.0, 0.0))
+///         .select("eiffel-tower"),
+///     TourStop::new(CameraView::new(12.4922, 41.8902, 1500.0))
+///         .duration(6.0)
+///         .easing(EasingFunction::QuadraticInOut),
+/// ];
+///
+/// view! {
+///     <ViewerContainer>
+///         <CameraTour stops=stops playing=playing current=current looping=true />
+///     </ViewerContainer>
+///     <button on:click=move |_| playing.update(|p| *p = !*p)>"Play/Pause"</button>
+///     <button on:click=move |_| current.update(|i| *i += 1)>"Skip"</button>
+///     <p>"Stop " {move || current.get() + 1}</p>
+/// }
+/// ```
+#[component(transparent)]
+pub fn CameraTour(
+    /// Stops in tour order
+    #[prop(into)]
+    stops: Signal<Vec<TourStop>>,
+    /// Whether the tour is playing (default: starts playing)
+    #[prop(optional, into)]
+    playing: Option<RwSignal<bool>>,
+    /// Index of the current stop; write it to skip
+    #[prop(optional, into)]
+    current: Option<RwSignal<usize>>,
+    /// Start over after the last stop (default: false)
+    #[prop(optional, into)]
+    looping: Signal<bool>,
+    /// Called when user input pauses the tour
+    #[prop(optional, into)]
+    on_interrupt: Option<UnsyncCallback<()>>,
+    /// Called when the last stop's dwell time is over and the tour doesn't loop
+    #[prop(optional, into)]
+    on_finish: Option<UnsyncCallback<()>>,
+) -> impl IntoView {
+    let playing = playing.unwrap_or_else(|| RwSignal::new(true));
+    let current = current.unwrap_or_else(|| RwSignal::new(0));
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context =
+            use_cesium_context().expect("CameraTour must be inside ViewerContainer");
+        // Incremented whenever the tour changes course, so stale legs stop
+        let generation = StoredValue::new(0u64);
+        let handler: JsStoredValue<Option<InterruptHandler>> = StoredValue::new_local(None);
+
+        let interrupt = move || {
+            if playing.try_get_untracked() == Some(true) {
+                playing.set(false);
+                if let Some(on_interrupt) = on_interrupt {
+                    on_interrupt.try_run(());
+                }
+            }
+        };
+
+        // Play the current stop; finishing it advances `current`, which plays the next one
+        Effect::new(move |was_playing: Option<bool>| {
+            let is_playing = playing.get();
+            let index = current.get();
+            let stops = stops.get();
+            let Some(viewer) = viewer_context.viewer() else {
+                return is_playing;
+            };
+
+            generation.update_value(|generation| *generation += 1);
+            let run = generation.get_value();
+            if !is_playing {
+                if was_playing == Some(true) {
+                    viewer.camera().cancel_flight();
+                }
+                return false;
+            }
+
+            let Some(stop) = stops.get(index).cloned() else {
+                // The stops changed under the tour
+                if looping.get_untracked() && !stops.is_empty() {
+                    current.set(0);
+                } else {
+                    playing.set(false);
+                }
+                return is_playing;
+            };
+            let count = stops.len();
+
+            let mut options = stop.view.fly_to_options();
+            if let Some(duration) = stop.duration {
+                options = options.duration(duration);
+            }
+            if let Some(easing) = stop.easing {
+                options = options.easing_function(easing);
+            }
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let is_stale = move || generation.try_get_value() != Some(run);
+
+                let outcome = fly_to(&viewer, options).await;
+                if is_stale() {
+                    return;
+                }
+                match outcome {
+                    Ok(FlightOutcome::Completed) => {}
+                    // Someone else moved the camera
+                    Ok(FlightOutcome::Cancelled) => {
+                        interrupt();
+                        return;
+                    }
+                    Err(error) => {
+                        web_sys::console::error_1(&JsValue::from_str(&format!(
+                            "CameraTour: flight to stop {index} failed: {error}"
+                        )));
+                        playing.set(false);
+                        return;
+                    }
+                }
+
+                if let Some(id) = &stop.select {
+                    let entity = viewer_context
+                        .with_viewer(|viewer| find_entity(&viewer, id))
+                        .flatten();
+                    viewer_context.set_selected_entity(entity);
+                }
+
+                let dwell_ms = (stop.dwell.max(0.0) * 1000.0) as u32;
+                gloo_timers::future::TimeoutFuture::new(dwell_ms).await;
+                if is_stale() {
+                    return;
+                }
+
+                if index + 1 < count {
+                    current.set(index + 1);
+                } else if looping.get_untracked() {
+                    current.set(0);
+                } else {
+                    playing.set(false);
+                    if let Some(on_finish) = on_finish {
+                        on_finish.try_run(());
+                    }
+                }
+            });
+            is_playing
+        });
+
+        // Pause when the user grabs the camera
+        Effect::new(move |_| {
+            let Some(viewer) = viewer_context.viewer() else {
+                return;
+            };
+            destroy_interrupt_handler(handler);
+
+            let screen_space_handler = ScreenSpaceEventHandler::new(&viewer.scene().canvas());
+            let on_input = Closure::wrap(
+                Box::new(move |_event: JsValue| interrupt()) as Box<dyn FnMut(JsValue)>
+            );
+            for kind in USER_CAMERA_INPUTS {
+                screen_space_handler.set_input_action(on_input.as_ref().unchecked_ref(), kind);
+            }
+            handler.set_value(Some((screen_space_handler, on_input)));
+        });
+
+        on_cleanup(move || {
+            generation.update_value(|generation| *generation += 1);
+            destroy_interrupt_handler(handler);
+            if playing.try_get_untracked() == Some(true) {
+                viewer_context.with_viewer(|viewer| viewer.camera().cancel_flight());
+            }
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (stops, playing, current, looping, on_interrupt, on_finish);
+    }
+}
@@ -0,0 +1,107 @@
+//! Shareable links: mirror the camera view into a URL query parameter
+
+use leptos::prelude::*;
+use leptos_router::{NavigateOptions, hooks::query_signal_with_options};
+
+use crate::bindings::CameraView;
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{EventListener, Viewer};
+#[cfg(target_arch = "wasm32")]
+use crate::components::use_cesium_context;
+#[cfg(target_arch = "wasm32")]
+use crate::core::JsStoredValue;
+#[cfg(target_arch = "wasm32")]
+use gloo_timers::callback::Timeout;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::closure::Closure;
+
+/// Camera URL sync component keeping the current view in a query parameter
+///
+/// The view is written as `longitude,latitude,height,heading,pitch,roll` once the camera has
+/// stopped moving for `debounce_ms`, replacing the history entry rather than adding one. When
+/// the page loads with the parameter set, the camera starts at that view. Must be inside both a
+/// `<Router>` and a `ViewerContainer`; requires the `router` feature.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// view! {
+///     <Router>
+///         <ViewerContainer>
+///             <CameraUrlSync param="view" />
+///         </ViewerContainer>
+///     </Router>
+/// }
+/// ```
+#[component(transparent)]
+pub fn CameraUrlSync(
+    /// Query parameter holding the view (default: "view")
+    #[prop(optional, into, default = "view".into())]
+    param: String,
+    /// Milliseconds the camera must be still before the URL is updated (default: 500)
+    #[prop(optional, default = 500)]
+    debounce_ms: u32,
+) -> impl IntoView {
+    let (view, set_view) = query_signal_with_options::<CameraView>(
+        param,
+        NavigateOptions {
+            replace: true,
+            scroll: false,
+            ..Default::default()
+        },
+    );
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context =
+            use_cesium_context().expect("CameraUrlSync must be inside ViewerContainer");
+        // Dropping a pending timeout cancels it
+        let pending: JsStoredValue<Option<Timeout>> = StoredValue::new_local(None);
+        let listener: JsStoredValue<Option<EventListener<dyn FnMut()>>> =
+            StoredValue::new_local(None);
+
+        Effect::new(move |_| {
+            let Some(viewer) = viewer_context.viewer() else {
+                return;
+            };
+            listener.set_value(None);
+
+            if let Some(shared) = view.get_untracked() {
+                shared.apply(&viewer, false);
+            }
+
+            let on_move_end = Closure::wrap(Box::new(move || {
+                let timeout = Timeout::new(debounce_ms, move || {
+                    let Some(current) =
+                        viewer_context.with_viewer(|viewer: Viewer| CameraView::capture(&viewer))
+                    else {
+                        return;
+                    };
+                    // Compare the URL form, since the URL only keeps rounded values
+                    let unchanged = view.with_untracked(|shared| {
+                        shared.map(|shared| shared.to_string()) == Some(current.to_string())
+                    });
+                    if !unchanged {
+                        set_view.set(Some(current));
+                    }
+                });
+                pending.set_value(Some(timeout));
+            }) as Box<dyn FnMut()>);
+            listener.set_value(Some(EventListener::new(
+                viewer.camera().move_end(),
+                on_move_end,
+            )));
+        });
+
+        on_cleanup(move || {
+            listener.try_update_value(Option::take);
+            pending.try_update_value(Option::take);
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (view, set_view, debounce_ms);
+    }
+}
@@ -0,0 +1,134 @@
+//! Entity clustering shared by the data source components
+
+use leptos::prelude::*;
+
+use crate::bindings::{Cluster, ClusterOptions, Entity};
+use crate::components::CesiumDataSourceContext;
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{
+    DataSource, EventListener, PositionedEvent, ScreenSpaceEventHandler, ScreenSpaceEventType,
+    Viewer,
+};
+#[cfg(target_arch = "wasm32")]
+use crate::components::{camera::destroy_handler, use_cesium_context};
+#[cfg(target_arch = "wasm32")]
+use crate::core::JsStoredValue;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+
+/// Callback invoked for every new cluster with the clustered entities and the cluster primitives
+pub type ClusterCallback = UnsyncCallback<(Vec<Entity>, Cluster)>;
+
+/// Listener of an `EntityCluster`'s `clusterEvent`
+#[cfg(target_arch = "wasm32")]
+type ClusterListener = EventListener<dyn FnMut(js_sys::Array, Cluster)>;
+
+/// Keep the clustering of the data source held by `data_source_context` in sync with
+/// `clustering`, run `on_cluster` for new clusters and fly to a cluster's entities on click
+/// when requested.
+pub(crate) fn bind_clustering(
+    data_source_context: CesiumDataSourceContext,
+    clustering: Signal<Option<ClusterOptions>>,
+    on_cluster: Option<ClusterCallback>,
+) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context =
+            use_cesium_context().expect("Clustering must be inside ViewerContainer");
+        let cluster_listener: JsStoredValue<Option<ClusterListener>> = StoredValue::new_local(None);
+        let click_handler: JsStoredValue<Option<ScreenSpaceEventHandler>> =
+            StoredValue::new_local(None);
+
+        // Apply clustering options whenever they or the data source change
+        Effect::new(move |_| {
+            let options = clustering.get();
+            data_source_context.with_data_source(|data_source: DataSource| {
+                let cluster = data_source.clustering();
+                match options {
+                    Some(options) => options.apply(&cluster),
+                    None => cluster.set_enabled(false),
+                }
+            });
+            viewer_context.request_render();
+        });
+
+        // Listen for new clusters on the current data source
+        Effect::new(move |_| {
+            cluster_listener.set_value(None);
+
+            let Some(data_source) = data_source_context.data_source() else {
+                return;
+            };
+            let listener =
+                Closure::wrap(Box::new(move |entities: js_sys::Array, cluster: Cluster| {
+                    // Cesium only tags the label with the clustered entities; tag every primitive
+                    // so picking any part of the cluster resolves to them
+                    let ids = cluster.label().id();
+                    cluster.billboard().set_id(&ids);
+                    cluster.point().set_id(&ids);
+
+                    if let Some(on_cluster) = on_cluster {
+                        let entities = entities
+                            .iter()
+                            .map(|entity| entity.unchecked_into::<Entity>())
+                            .collect();
+                        on_cluster.run((entities, cluster));
+                    }
+                }) as Box<dyn FnMut(js_sys::Array, Cluster)>);
+
+            cluster_listener.set_value(Some(EventListener::new(
+                data_source.clustering().cluster_event(),
+                listener,
+            )));
+        });
+
+        // Fly to the clustered entities when a cluster is clicked
+        Effect::new(move |_| {
+            let expand = clustering.with(|options| {
+                options
+                    .as_ref()
+                    .is_some_and(ClusterOptions::expands_on_click)
+            });
+
+            destroy_handler(click_handler);
+            if !expand {
+                return;
+            }
+
+            viewer_context.with_viewer(|viewer: Viewer| {
+                let handler = ScreenSpaceEventHandler::new(&viewer.scene().canvas());
+                let action = Closure::wrap(Box::new(move |event: PositionedEvent| {
+                    viewer_context.with_viewer(|viewer: Viewer| {
+                        let picked = viewer.scene().pick(&event.position());
+                        if picked.is_undefined() {
+                            return;
+                        }
+                        let Ok(ids) = js_sys::Reflect::get(&picked, &JsValue::from_str("id"))
+                        else {
+                            return;
+                        };
+                        if js_sys::Array::is_array(&ids) {
+                            let _ = viewer.fly_to(&ids);
+                        }
+                    });
+                }) as Box<dyn FnMut(PositionedEvent)>)
+                .into_js_value()
+                .unchecked_into::<js_sys::Function>();
+
+                handler.set_input_action(&action, ScreenSpaceEventType::LeftClick);
+                click_handler.set_value(Some(handler));
+            });
+        });
+
+        on_cleanup(move || {
+            cluster_listener.try_update_value(Option::take);
+            destroy_handler(click_handler);
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (data_source_context, clustering, on_cluster);
+    }
+}
@@ -1,15 +1,22 @@
 //! Context types wiring Cesium state through the Leptos component tree.
 
+use std::collections::HashSet;
+
 use leptos::prelude::*;
 use wasm_bindgen::{JsCast, JsValue};
 
 #[cfg(not(feature = "ssr"))]
 use crate::core::JsRwSignal;
 use crate::{
-    bindings::Entity,
+    bindings::{Cesium3DTileFeature, DataSource, Entity, SceneMode},
     cesium::Viewer,
     core::{JsReadSignal, ThreadSafeJsValue},
 };
+#[cfg(target_arch = "wasm32")]
+use crate::{
+    bindings::{ImageryLayer, ImageryLayerCollection},
+    core::JsStoredValue,
+};
 
 /// Context exposing the active Cesium viewer to descendants.
 #[derive(Debug, Clone, Copy)]
@@ -23,11 +30,35 @@ pub struct CesiumViewerContext {
     #[cfg(not(feature = "ssr"))]
     selection_version: RwSignal<usize>,
     #[cfg(not(feature = "ssr"))]
+    selected_feature: JsRwSignal<Option<ThreadSafeJsValue<JsValue>>>,
+    /// Entity followed by the camera, kept in sync with `viewer.trackedEntity`
+    #[cfg(not(feature = "ssr"))]
+    tracked_entity: JsRwSignal<Option<ThreadSafeJsValue<JsValue>>>,
+    /// Scene mode, updated when a morph completes
+    #[cfg(not(feature = "ssr"))]
+    scene_mode: RwSignal<SceneMode>,
+    /// Ids of the entities in the multi-selection
+    #[cfg(not(feature = "ssr"))]
+    selected_entities: RwSignal<HashSet<String>>,
+    /// Mounted `ImageryLayer` components and their Cesium layers, used to keep layer order
+    #[cfg(target_arch = "wasm32")]
+    imagery_layers: JsStoredValue<ImageryLayerOrder>,
+    #[cfg(not(feature = "ssr"))]
     thread_id: std::thread::ThreadId,
     #[cfg(feature = "ssr")]
     _phantom: std::marker::PhantomData<()>,
 }
 
+/// Registration order of the `ImageryLayer` components of a viewer
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Default)]
+struct ImageryLayerOrder {
+    /// Position handed to the next registered component
+    next: usize,
+    /// Position and current Cesium layer of each registered component
+    layers: Vec<(usize, ImageryLayer)>,
+}
+
 impl CesiumViewerContext {
     /// Create a fresh viewer context.
     pub fn new() -> Self {
@@ -36,10 +67,18 @@ impl CesiumViewerContext {
             viewer: JsRwSignal::new_local(None),
             selected_entity: JsRwSignal::new_local(None),
             selection_version: RwSignal::new(0),
+            selected_feature: JsRwSignal::new_local(None),
+            tracked_entity: JsRwSignal::new_local(None),
+            scene_mode: RwSignal::new(SceneMode::default()),
+            selected_entities: RwSignal::new(HashSet::new()),
+            #[cfg(target_arch = "wasm32")]
+            imagery_layers: StoredValue::new_local(ImageryLayerOrder::default()),
             thread_id: std::thread::current().id(),
         };
         #[cfg(feature = "ssr")]
         Self {
+            #[cfg(target_arch = "wasm32")]
+            imagery_layers: StoredValue::new_local(ImageryLayerOrder::default()),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -139,6 +178,72 @@ impl CesiumViewerContext {
         None
     }
 
+    /// Ask Cesium to render a new frame.
+    ///
+    /// Components call this after changing the scene so that it stays up to date when the
+    /// viewer uses request render mode. Does nothing before the viewer exists or after it is gone.
+    #[cfg(not(feature = "ssr"))]
+    pub fn request_render(&self) {
+        if let Some(value) = self.viewer.try_get_untracked().flatten() {
+            value
+                .value()
+                .unchecked_ref::<Viewer>()
+                .scene()
+                .request_render();
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn request_render(&self) {
+        // No-op during SSR
+    }
+
+    /// Register an `ImageryLayer` component, returning its position in the layer order
+    ///
+    /// Components registered later draw on top of earlier ones.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn register_imagery_layer(&self) -> usize {
+        self.imagery_layers
+            .try_update_value(|order| {
+                let position = order.next;
+                order.next += 1;
+                position
+            })
+            .unwrap_or_default()
+    }
+
+    /// Index in `layers` that puts a layer at `position` above the layers of earlier
+    /// components and below those of later ones
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn imagery_layer_index(
+        &self,
+        position: usize,
+        layers: &ImageryLayerCollection,
+    ) -> usize {
+        self.imagery_layers
+            .try_with_value(|order| {
+                order
+                    .layers
+                    .iter()
+                    .filter(|(other, _)| *other > position)
+                    .filter_map(|(_, layer)| usize::try_from(layers.index_of(layer)).ok())
+                    .min()
+            })
+            .flatten()
+            .unwrap_or_else(|| layers.length())
+    }
+
+    /// Record the Cesium layer of the component at `position`, or forget it with `None`
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn set_imagery_layer(&self, position: usize, layer: Option<ImageryLayer>) {
+        self.imagery_layers.try_update_value(|order| {
+            order.layers.retain(|(other, _)| *other != position);
+            if let Some(layer) = layer {
+                order.layers.push((position, layer));
+            }
+        });
+    }
+
     /// Set the selected entity (strongly-typed).
     #[cfg(not(feature = "ssr"))]
     pub fn set_selected_entity(&self, entity: Option<Entity>) {
@@ -273,6 +378,398 @@ impl CesiumViewerContext {
         panic!("selection_version() is not available during SSR");
     }
 
+    /// Set the selected 3D Tiles feature (set by tileset components when a feature is clicked).
+    #[cfg(not(feature = "ssr"))]
+    pub fn set_selected_feature(&self, feature: Option<Cesium3DTileFeature>) {
+        if !self.is_valid() {
+            leptos::logging::error!(
+                "Accessing Cesium viewer from a different thread. Probably running on the server."
+            );
+            return;
+        }
+        self.selected_feature.set(feature.map(|feature| {
+            let value: JsValue = feature.into();
+            ThreadSafeJsValue::new(value)
+        }));
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn set_selected_feature(&self, feature: Option<Cesium3DTileFeature>) {
+        let _ = feature;
+    }
+
+    /// Returns the selected 3D Tiles feature.
+    #[cfg(not(feature = "ssr"))]
+    pub fn selected_feature(&self) -> Option<Cesium3DTileFeature> {
+        self.selected_feature.get().map(|value| {
+            value
+                .value()
+                .clone()
+                .unchecked_into::<Cesium3DTileFeature>()
+        })
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn selected_feature(&self) -> Option<Cesium3DTileFeature> {
+        None
+    }
+
+    /// Returns the selected 3D Tiles feature without tracking reactive dependencies.
+    #[cfg(not(feature = "ssr"))]
+    pub fn selected_feature_untracked(&self) -> Option<Cesium3DTileFeature> {
+        self.selected_feature.get_untracked().map(|value| {
+            value
+                .value()
+                .clone()
+                .unchecked_into::<Cesium3DTileFeature>()
+        })
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn selected_feature_untracked(&self) -> Option<Cesium3DTileFeature> {
+        None
+    }
+
+    /// Clear the selected 3D Tiles feature.
+    #[cfg(not(feature = "ssr"))]
+    pub fn clear_selected_feature(&self) {
+        if self.is_valid() {
+            self.selected_feature.set(None);
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn clear_selected_feature(&self) {
+        // No-op during SSR
+    }
+
+    /// Make the camera follow an entity, or stop following with `None`.
+    #[cfg(not(feature = "ssr"))]
+    pub fn set_tracked_entity(&self, entity: Option<Entity>) {
+        if !self.is_valid() {
+            leptos::logging::error!(
+                "Accessing Cesium viewer from a different thread. Probably running on the server."
+            );
+            return;
+        }
+        self.tracked_entity.set(entity.map(|entity| {
+            let value: JsValue = entity.into();
+            ThreadSafeJsValue::new(value)
+        }));
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn set_tracked_entity(&self, entity: Option<Entity>) {
+        let _ = entity;
+    }
+
+    /// Set the tracked entity from a JsValue (used internally by event listener).
+    #[cfg(not(feature = "ssr"))]
+    #[allow(dead_code)] // Called from wasm32-only code in viewer_container.rs
+    pub(crate) fn set_tracked_entity_from_js(&self, entity: JsValue) {
+        if !self.is_valid() {
+            return;
+        }
+        if entity.is_undefined() || entity.is_null() {
+            self.tracked_entity.set(None);
+        } else {
+            self.tracked_entity
+                .set(Some(ThreadSafeJsValue::new(entity)));
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    #[allow(dead_code)]
+    pub(crate) fn set_tracked_entity_from_js(&self, entity: JsValue) {
+        let _ = entity;
+    }
+
+    /// Returns the entity followed by the camera.
+    #[cfg(not(feature = "ssr"))]
+    pub fn tracked_entity(&self) -> Option<Entity> {
+        self.tracked_entity
+            .get()
+            .map(|value| value.value().clone().unchecked_into::<Entity>())
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn tracked_entity(&self) -> Option<Entity> {
+        None
+    }
+
+    /// Returns the entity followed by the camera without tracking reactive dependencies.
+    #[cfg(not(feature = "ssr"))]
+    pub fn tracked_entity_untracked(&self) -> Option<Entity> {
+        self.tracked_entity
+            .get_untracked()
+            .map(|value| value.value().clone().unchecked_into::<Entity>())
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn tracked_entity_untracked(&self) -> Option<Entity> {
+        None
+    }
+
+    /// Returns a read/write signal for the tracked entity.
+    ///
+    /// Writes make the camera follow the entity; the signal is updated when the user
+    /// starts or stops tracking through Cesium's own UI.
+    #[cfg(not(feature = "ssr"))]
+    pub fn tracked_entity_signal(&self) -> JsRwSignal<Option<ThreadSafeJsValue<JsValue>>> {
+        if self.is_valid() {
+            self.tracked_entity
+        } else {
+            panic!(
+                "Accessing Cesium viewer from a different thread. Probably running on the server."
+            );
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn tracked_entity_signal(
+        &self,
+    ) -> crate::core::JsRwSignal<Option<ThreadSafeJsValue<JsValue>>> {
+        panic!("tracked_entity_signal() is not available during SSR");
+    }
+
+    /// Stop following an entity with the camera.
+    #[cfg(not(feature = "ssr"))]
+    pub fn clear_tracked_entity(&self) {
+        if self.is_valid() {
+            self.tracked_entity.set(None);
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn clear_tracked_entity(&self) {
+        // No-op during SSR
+    }
+
+    /// Record the scene mode (used internally when a morph completes).
+    #[cfg(not(feature = "ssr"))]
+    #[allow(dead_code)] // Called from wasm32-only code in viewer_container.rs
+    pub(crate) fn set_scene_mode(&self, mode: SceneMode) {
+        if self.is_valid() && self.scene_mode.get_untracked() != mode {
+            self.scene_mode.set(mode);
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    #[allow(dead_code)]
+    pub(crate) fn set_scene_mode(&self, mode: SceneMode) {
+        let _ = mode;
+    }
+
+    /// Returns the scene mode the viewer last finished morphing to.
+    #[cfg(not(feature = "ssr"))]
+    pub fn scene_mode(&self) -> SceneMode {
+        self.scene_mode.get()
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn scene_mode(&self) -> SceneMode {
+        SceneMode::default()
+    }
+
+    /// Returns the scene mode without tracking reactive dependencies.
+    #[cfg(not(feature = "ssr"))]
+    pub fn scene_mode_untracked(&self) -> SceneMode {
+        self.scene_mode.get_untracked()
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn scene_mode_untracked(&self) -> SceneMode {
+        SceneMode::default()
+    }
+
+    /// Add an entity to the multi-selection.
+    #[cfg(not(feature = "ssr"))]
+    pub fn select(&self, id: impl Into<String>) {
+        let id = id.into();
+        if self.is_valid() {
+            self.selected_entities.maybe_update(|ids| ids.insert(id));
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn select(&self, id: impl Into<String>) {
+        let _ = id.into();
+    }
+
+    /// Add several entities to the multi-selection.
+    #[cfg(not(feature = "ssr"))]
+    pub fn select_many<I, S>(&self, ids: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let ids: Vec<String> = ids.into_iter().map(Into::into).collect();
+        if self.is_valid() {
+            self.selected_entities.maybe_update(|selected| {
+                let count = selected.len();
+                selected.extend(ids);
+                selected.len() != count
+            });
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn select_many<I, S>(&self, ids: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let _ = ids;
+    }
+
+    /// Replace the multi-selection with the given entities.
+    #[cfg(not(feature = "ssr"))]
+    pub fn set_selection<I, S>(&self, ids: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let ids: HashSet<String> = ids.into_iter().map(Into::into).collect();
+        if self.is_valid() {
+            self.selected_entities.maybe_update(|selected| {
+                let changed = *selected != ids;
+                if changed {
+                    *selected = ids;
+                }
+                changed
+            });
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn set_selection<I, S>(&self, ids: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let _ = ids;
+    }
+
+    /// Remove an entity from the multi-selection.
+    #[cfg(not(feature = "ssr"))]
+    pub fn deselect(&self, id: &str) {
+        if self.is_valid() {
+            self.selected_entities.maybe_update(|ids| ids.remove(id));
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn deselect(&self, id: &str) {
+        let _ = id;
+    }
+
+    /// Add an entity to the multi-selection, or remove it when already selected.
+    #[cfg(not(feature = "ssr"))]
+    pub fn toggle(&self, id: impl Into<String>) {
+        let id = id.into();
+        if self.is_valid() {
+            self.selected_entities.update(|ids| {
+                if !ids.remove(&id) {
+                    ids.insert(id);
+                }
+            });
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn toggle(&self, id: impl Into<String>) {
+        let _ = id.into();
+    }
+
+    /// Empty the multi-selection.
+    #[cfg(not(feature = "ssr"))]
+    pub fn clear_selection(&self) {
+        if self.is_valid() {
+            self.selected_entities.maybe_update(|ids| {
+                let changed = !ids.is_empty();
+                ids.clear();
+                changed
+            });
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn clear_selection(&self) {
+        // No-op during SSR
+    }
+
+    /// Returns whether an entity is in the multi-selection.
+    #[cfg(not(feature = "ssr"))]
+    pub fn is_selected(&self, id: &str) -> bool {
+        self.selected_entities.with(|ids| ids.contains(id))
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn is_selected(&self, id: &str) -> bool {
+        let _ = id;
+        false
+    }
+
+    /// Returns the ids of the entities in the multi-selection.
+    #[cfg(not(feature = "ssr"))]
+    pub fn selected_ids(&self) -> HashSet<String> {
+        self.selected_entities.get()
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn selected_ids(&self) -> HashSet<String> {
+        HashSet::new()
+    }
+
+    /// Returns the selected ids without tracking reactive dependencies.
+    #[cfg(not(feature = "ssr"))]
+    pub fn selected_ids_untracked(&self) -> HashSet<String> {
+        self.selected_entities.get_untracked()
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn selected_ids_untracked(&self) -> HashSet<String> {
+        HashSet::new()
+    }
+
+    /// Returns the selected entities found in the viewer or its data sources.
+    ///
+    /// Ids whose entity has been removed are skipped.
+    #[cfg(not(feature = "ssr"))]
+    pub fn selected_entities(&self) -> Vec<Entity> {
+        let ids = self.selected_entities.get();
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.with_viewer(|viewer: Viewer| {
+                ids.iter()
+                    .filter_map(|id| crate::components::camera::find_entity(&viewer, id))
+                    .collect()
+            })
+            .unwrap_or_default()
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = ids;
+            Vec::new()
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn selected_entities(&self) -> Vec<Entity> {
+        Vec::new()
+    }
+
+    /// Access the multi-selection signal directly.
+    #[cfg(not(feature = "ssr"))]
+    pub fn selected_entities_signal(&self) -> RwSignal<HashSet<String>> {
+        self.selected_entities
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn selected_entities_signal(&self) -> RwSignal<HashSet<String>> {
+        panic!("selected_entities_signal() is not available during SSR");
+    }
+
     #[cfg(not(feature = "ssr"))]
     fn is_valid(&self) -> bool {
         std::thread::current().id() == self.thread_id && !self.viewer.is_disposed()
@@ -435,3 +932,162 @@ pub fn extend_context_with_entity() -> CesiumEntityContext {
 pub fn use_entity_context() -> Option<CesiumEntityContext> {
     use_context::<CesiumEntityContext>()
 }
+
+/// Context exposing the data source that child entities should be added to.
+#[derive(Debug, Clone, Copy)]
+pub struct CesiumDataSourceContext {
+    #[cfg(not(feature = "ssr"))]
+    data_source: JsRwSignal<Option<ThreadSafeJsValue<JsValue>>>,
+    #[cfg(not(feature = "ssr"))]
+    thread_id: std::thread::ThreadId,
+    #[cfg(feature = "ssr")]
+    _phantom: std::marker::PhantomData<()>,
+}
+
+impl CesiumDataSourceContext {
+    pub fn new() -> Self {
+        #[cfg(not(feature = "ssr"))]
+        return Self {
+            data_source: JsRwSignal::new_local(None),
+            thread_id: std::thread::current().id(),
+        };
+        #[cfg(feature = "ssr")]
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Record the data source instance in the context.
+    #[cfg(not(feature = "ssr"))]
+    pub fn set_data_source(&self, data_source: impl Into<DataSource>) {
+        if !self.is_valid() {
+            leptos::logging::error!(
+                "Accessing Cesium data source from a different thread. Probably running on the server."
+            );
+            return;
+        }
+        let value: JsValue = data_source.into().into();
+        self.data_source.set(Some(ThreadSafeJsValue::new(value)));
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn set_data_source(&self, data_source: impl Into<DataSource>) {
+        let _ = data_source;
+    }
+
+    /// Returns the data source, tracking reactive dependencies.
+    #[cfg(not(feature = "ssr"))]
+    pub fn data_source(&self) -> Option<DataSource> {
+        if !self.is_valid() {
+            return None;
+        }
+        self.data_source
+            .get()
+            .map(|value| value.value().clone().unchecked_into::<DataSource>())
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn data_source(&self) -> Option<DataSource> {
+        None
+    }
+
+    /// Returns the data source without tracking reactive dependencies.
+    #[cfg(not(feature = "ssr"))]
+    pub fn data_source_untracked(&self) -> Option<DataSource> {
+        if !self.is_valid() {
+            return None;
+        }
+        self.data_source
+            .get_untracked()
+            .map(|value| value.value().clone().unchecked_into::<DataSource>())
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn data_source_untracked(&self) -> Option<DataSource> {
+        None
+    }
+
+    /// Clears the data source reference from the context.
+    #[cfg(not(feature = "ssr"))]
+    pub fn clear_data_source(&self) {
+        if self.is_valid() {
+            self.data_source.set(None);
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub fn clear_data_source(&self) {
+        // No-op during SSR
+    }
+
+    /// Executes a closure with the data source reference if it is available.
+    pub fn with_data_source<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(DataSource) -> R,
+    {
+        self.data_source().map(f)
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn is_valid(&self) -> bool {
+        std::thread::current().id() == self.thread_id && !self.data_source.is_disposed()
+    }
+
+    #[cfg(feature = "ssr")]
+    #[allow(dead_code)] // Used by hydrate-gated methods
+    fn is_valid(&self) -> bool {
+        false
+    }
+}
+
+impl Default for CesiumDataSourceContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Retrieve the current data source context.
+pub fn use_data_source_context() -> Option<CesiumDataSourceContext> {
+    use_context::<CesiumDataSourceContext>()
+}
+
+#[cfg(all(test, not(feature = "ssr")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_and_deselect() {
+        let context = CesiumViewerContext::new();
+        context.select("a");
+        context.select_many(["b", "c"]);
+        assert!(context.is_selected("a"));
+        assert!(context.is_selected("c"));
+
+        context.deselect("b");
+        assert!(!context.is_selected("b"));
+        assert_eq!(context.selected_ids_untracked().len(), 2);
+    }
+
+    #[test]
+    fn test_toggle() {
+        let context = CesiumViewerContext::new();
+        context.toggle("a");
+        assert!(context.is_selected("a"));
+        context.toggle("a");
+        assert!(!context.is_selected("a"));
+    }
+
+    #[test]
+    fn test_set_and_clear_selection() {
+        let context = CesiumViewerContext::new();
+        context.select_many(["a", "b"]);
+        context.set_selection(["c"]);
+        assert_eq!(
+            context.selected_ids_untracked(),
+            HashSet::from(["c".to_string()])
+        );
+
+        context.clear_selection();
+        assert!(context.selected_ids_untracked().is_empty());
+    }
+}
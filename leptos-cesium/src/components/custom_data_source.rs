@@ -0,0 +1,117 @@
+//! Custom data source component for grouping entities
+
+use leptos::{context::Provider, prelude::*};
+
+use crate::bindings::ClusterOptions;
+use crate::components::{CesiumDataSourceContext, ClusterCallback, bind_clustering};
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{CustomDataSource as CesiumCustomDataSource, DataSource, Viewer};
+#[cfg(target_arch = "wasm32")]
+use crate::components::use_cesium_context;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, JsValue};
+#[cfg(target_arch = "wasm32")]
+use web_sys::console;
+
+/// Custom data source component for grouping entities
+///
+/// Child `<Entity>` components are added to this data source's entity collection instead of
+/// the viewer's, so the whole group can be shown, hidden or removed together. Unmounting the
+/// component removes the data source and every entity in it.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let (show_poi, set_show_poi) = signal(true);
+///
+/// view! {
+///     <ViewerContainer ion_token=token>
+///         <CustomDataSource name="Points of interest".to_string() show=show_poi>
+///             <Entity name=Some("Statue of Liberty".to_string())
+///                 position=Some(Cartesian3::from_degrees(-74.0445, 40.6892, 150.0))>
+///                 <PointGraphics pixel_size=12.0 />
+///             </Entity>
+///         </CustomDataSource>
+///     </ViewerContainer>
+/// }
+/// ```
+#[component]
+pub fn CustomDataSource(
+    /// Optional data source name
+    #[prop(optional, into)]
+    name: Signal<Option<String>>,
+    /// Whether to show the data source and its entities (default: true)
+    #[prop(optional, into, default = true.into())]
+    show: Signal<bool>,
+    /// Entity clustering options; `None` disables clustering
+    #[prop(optional, into)]
+    clustering: Signal<Option<ClusterOptions>>,
+    /// Called for every new cluster to restyle its billboard, label and point
+    #[prop(optional, into)]
+    on_cluster: Option<ClusterCallback>,
+    /// Child entity components
+    children: Children,
+) -> impl IntoView {
+    // Scoped to the children so sibling entities keep using the viewer's collection
+    let data_source_context = CesiumDataSourceContext::new();
+    bind_clustering(data_source_context, clustering, on_cluster);
+
+    #[cfg(target_arch = "wasm32")]
+    let viewer_context =
+        use_cesium_context().expect("CustomDataSource must be inside ViewerContainer");
+
+    Effect::new(move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            if data_source_context.data_source_untracked().is_some() {
+                return;
+            }
+
+            viewer_context.with_viewer(|viewer: Viewer| {
+                console::debug_1(&JsValue::from_str("CustomDataSource: creating data source"));
+                let data_source = CesiumCustomDataSource::new(name.get_untracked().as_deref());
+                data_source.set_show(show.get_untracked());
+
+                let _ = viewer.data_sources().add_data_source(&data_source);
+                data_source_context.set_data_source(data_source);
+            });
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = (name, show);
+        }
+    });
+
+    // Keep name and visibility in sync without recreating the data source
+    #[cfg(target_arch = "wasm32")]
+    Effect::new(move |_| {
+        let name = name.get();
+        let show = show.get();
+        data_source_context.with_data_source(|data_source: DataSource| {
+            let data_source = data_source.unchecked_into::<CesiumCustomDataSource>();
+            if let Some(name) = name {
+                data_source.set_name(&name);
+            }
+            data_source.set_show(show);
+        });
+        viewer_context.request_render();
+    });
+
+    on_cleanup(move || {
+        #[cfg(target_arch = "wasm32")]
+        {
+            if let Some(data_source) = data_source_context.data_source_untracked() {
+                viewer_context.with_viewer(|viewer: Viewer| {
+                    viewer.data_sources().remove(&data_source, true);
+                    console::debug_1(&JsValue::from_str("CustomDataSource: removed from viewer"));
+                });
+                viewer_context.request_render();
+            }
+        }
+        data_source_context.clear_data_source();
+    });
+
+    view! { <Provider value=data_source_context>{children()}</Provider> }
+}
@@ -2,6 +2,9 @@
 
 use leptos::prelude::*;
 
+use crate::bindings::ClusterOptions;
+use crate::components::{CesiumDataSourceContext, ClusterCallback, bind_clustering};
+
 #[cfg(target_arch = "wasm32")]
 use crate::bindings::{Viewer, czml_data_source_load};
 #[cfg(target_arch = "wasm32")]
@@ -33,7 +36,17 @@ pub fn CzmlDataSource(
     /// Whether to remove all existing data sources before loading (default: true)
     #[prop(optional, into, default = true.into())]
     clear_existing: Signal<bool>,
+    /// Entity clustering options; `None` disables clustering
+    #[prop(optional, into)]
+    clustering: Signal<Option<ClusterOptions>>,
+    /// Called for every new cluster to restyle its billboard, label and point
+    #[prop(optional, into)]
+    on_cluster: Option<ClusterCallback>,
 ) -> impl IntoView {
+    // Holds the most recently loaded data source
+    let data_source_context = CesiumDataSourceContext::new();
+    bind_clustering(data_source_context, clustering, on_cluster);
+
     #[cfg(target_arch = "wasm32")]
     {
         let viewer_context =
@@ -68,6 +81,12 @@ pub fn CzmlDataSource(
                             use js_sys::Reflect;
                             use wasm_bindgen::JsCast;
 
+                            data_source_context.set_data_source(
+                                data_source_js
+                                    .clone()
+                                    .unchecked_into::<crate::bindings::DataSource>(),
+                            );
+
                             if let Ok(data_source) = data_source_js.dyn_into::<CzmlDataSource>() {
                                 let ds_clock = data_source.clock();
                                 viewer_ctx_clone.with_viewer(|v: Viewer| {
@@ -80,6 +99,7 @@ pub fn CzmlDataSource(
                                     v.clock().set_should_animate(true);
                                 });
                             }
+                            viewer_ctx_clone.request_render();
                         }
                         Err(e) => {
                             web_sys::console::error_1(&JsValue::from_str(&format!(
@@ -98,7 +118,9 @@ pub fn CzmlDataSource(
                 viewer_ctx.with_viewer(|viewer: Viewer| {
                     viewer.data_sources().remove_all();
                 });
+                viewer_ctx.request_render();
             }
+            data_source_context.clear_data_source();
         });
     }
 
@@ -10,7 +10,7 @@ use crate::bindings::Viewer;
 #[cfg(target_arch = "wasm32")]
 use crate::bindings::{Cartesian3, Entity as CesiumEntity};
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_cesium_context;
+use crate::components::{use_cesium_context, use_data_source_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -22,6 +22,28 @@ use web_sys::console;
 use crate::bindings::Cartesian3;
 
 /// Entity component for creating Cesium entities with graphics
+///
+/// Entities are added to the viewer's entity collection, or to the enclosing data source when
+/// placed inside a data source component such as `<CustomDataSource>`.
+///
+/// # Tracking Example
+///
+/// ```rust,ignore
+/// let (follow, set_follow) = signal(false);
+///
+/// view! {
+///     <ViewerContainer>
+///         <Entity
+///             position=vehicle_position
+///             track=follow
+///             view_from=Some(Cartesian3::new(-300.0, 0.0, 150.0))
+///         >
+///             <ModelGraphics uri="models/truck.glb" />
+///         </Entity>
+///     </ViewerContainer>
+///     <button on:click=move |_| set_follow.update(|f| *f = !*f)>"Follow"</button>
+/// }
+/// ```
 #[component]
 pub fn Entity(
     /// Optional entity name
@@ -36,6 +58,12 @@ pub fn Entity(
     /// Whether to show the entity
     #[prop(optional, into)]
     show: Signal<Option<bool>>,
+    /// Follow the entity with the camera while true
+    #[prop(optional, into)]
+    track: Signal<bool>,
+    /// Camera offset used when tracking or zooming to the entity, in its east-north-up frame
+    #[prop(optional, into)]
+    view_from: JsSignal<Option<Cartesian3>>,
     /// Child graphics components
     children: Children,
 ) -> impl IntoView {
@@ -43,6 +71,8 @@ pub fn Entity(
 
     #[cfg(target_arch = "wasm32")]
     let viewer_context = use_cesium_context().expect("Entity must be inside ViewerContainer");
+    #[cfg(target_arch = "wasm32")]
+    let data_source_context = use_data_source_context();
 
     Effect::new(move |_| {
         #[cfg(target_arch = "wasm32")]
@@ -53,9 +83,19 @@ pub fn Entity(
                 return;
             }
 
+            // Inside a data source component, wait until the data source exists
+            let data_source = data_source_context.and_then(|ctx| ctx.data_source());
+            if data_source_context.is_some() && data_source.is_none() {
+                console::debug_1(&JsValue::from_str("Entity: waiting for data source"));
+                return;
+            }
+
             viewer_context.with_viewer(|viewer: Viewer| {
                 console::debug_1(&JsValue::from_str("Entity: creating entity"));
-                let entities = viewer.entities();
+                let entities = match &data_source {
+                    Some(data_source) => data_source.entities(),
+                    None => viewer.entities(),
+                };
                 let entity_options = Object::new();
 
                 // Set name if provided
@@ -98,11 +138,41 @@ pub fn Entity(
                 console::debug_1(&JsValue::from_str("Entity: entity created"));
                 entity_context.set_entity(entity);
             });
+            viewer_context.request_render();
         }
 
         #[cfg(not(target_arch = "wasm32"))]
         {
-            let _ = (name, position, description, show);
+            let _ = (name, position, description, show, track, view_from);
+        }
+    });
+
+    // Follow the entity with the camera while `track` is set
+    #[cfg(target_arch = "wasm32")]
+    Effect::new(move |was_tracking: Option<bool>| {
+        let tracking = track.get();
+        let Some(entity) = entity_context.entity::<CesiumEntity>() else {
+            return tracking;
+        };
+        let is_tracked = viewer_context
+            .tracked_entity_untracked()
+            .is_some_and(|tracked| JsValue::from(tracked) == *entity);
+
+        if tracking && !is_tracked {
+            viewer_context.set_tracked_entity(Some(entity));
+        } else if !tracking && is_tracked && was_tracking == Some(true) {
+            // Only stop tracking we started, not tracking picked in Cesium's UI
+            viewer_context.clear_tracked_entity();
+        }
+        tracking
+    });
+
+    #[cfg(target_arch = "wasm32")]
+    Effect::new(move |_| {
+        let view_from = view_from.get();
+        if let Some(entity) = entity_context.entity::<CesiumEntity>() {
+            let value = view_from.map(JsValue::from).unwrap_or(JsValue::UNDEFINED);
+            let _ = Reflect::set(&entity, &JsValue::from_str("viewFrom"), &value);
         }
     });
 
@@ -110,13 +180,27 @@ pub fn Entity(
         #[cfg(target_arch = "wasm32")]
         {
             console::debug_1(&JsValue::from_str("Entity: cleanup"));
-            if let Some(entity) = entity_context.entity_untracked::<CesiumEntity>()
-                && let Some(viewer_context) = use_cesium_context()
-            {
-                viewer_context.with_viewer(|viewer: Viewer| {
-                    viewer.entities().remove(&entity);
-                    console::debug_1(&JsValue::from_str("Entity: removed from viewer"));
-                });
+            if let Some(entity) = entity_context.entity_untracked::<CesiumEntity>() {
+                let is_tracked = viewer_context
+                    .tracked_entity_untracked()
+                    .is_some_and(|tracked| JsValue::from(tracked) == *entity);
+                if is_tracked {
+                    viewer_context.clear_tracked_entity();
+                }
+                viewer_context.deselect(&entity.id());
+
+                if let Some(data_source) =
+                    data_source_context.and_then(|ctx| ctx.data_source_untracked())
+                {
+                    data_source.entities().remove(&entity);
+                    console::debug_1(&JsValue::from_str("Entity: removed from data source"));
+                } else if let Some(viewer_context) = use_cesium_context() {
+                    viewer_context.with_viewer(|viewer: Viewer| {
+                        viewer.entities().remove(&entity);
+                        console::debug_1(&JsValue::from_str("Entity: removed from viewer"));
+                    });
+                }
+                viewer_context.request_render();
             }
         }
         entity_context.clear_entity();
@@ -0,0 +1,137 @@
+//! 3D Tiles feature picking shared by the tileset components
+
+use leptos::prelude::*;
+
+use crate::bindings::Cesium3DTileFeature;
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{
+    Cesium3DTileset, Color, MotionEvent, PositionedEvent, ScreenSpaceEventHandler,
+    ScreenSpaceEventType, Viewer,
+};
+#[cfg(target_arch = "wasm32")]
+use crate::components::{camera::destroy_handler, use_cesium_context};
+#[cfg(target_arch = "wasm32")]
+use crate::core::{JsRwSignal, JsSignal, JsStoredValue};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+
+/// Callback invoked with a feature of the tileset that was clicked
+pub type FeatureClickCallback = UnsyncCallback<Cesium3DTileFeature>;
+
+/// Callback invoked when the pointer enters a feature of the tileset, or leaves it (`None`)
+pub type FeatureHoverCallback = UnsyncCallback<Option<Cesium3DTileFeature>>;
+
+/// Pick features of `tileset` under the pointer: clicks update the viewer's selected feature
+/// and run `on_click`, hovering runs `on_hover` and tints the feature with `hover_color`.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn bind_feature_picking(
+    tileset: JsRwSignal<Option<Cesium3DTileset>>,
+    on_click: Option<FeatureClickCallback>,
+    on_hover: Option<FeatureHoverCallback>,
+    hover_color: JsSignal<Option<Color>>,
+) {
+    let viewer_context =
+        use_cesium_context().expect("Feature picking must be inside ViewerContainer");
+    let handler: JsStoredValue<Option<ScreenSpaceEventHandler>> = StoredValue::new_local(None);
+    // The hovered feature and its color before highlighting
+    let hovered: JsStoredValue<Option<(Cesium3DTileFeature, Color)>> = StoredValue::new_local(None);
+
+    // Pick the topmost feature of this tileset at a window position
+    let pick = move |viewer: &Viewer, position| -> Option<Cesium3DTileFeature> {
+        let tileset = tileset.try_get_untracked().flatten()?;
+        viewer
+            .scene()
+            .pick(&position)
+            .dyn_into::<Cesium3DTileFeature>()
+            .ok()
+            .filter(|feature| feature.belongs_to(&tileset))
+    };
+
+    Effect::new(move |_| {
+        let hover_enabled = on_hover.is_some() || hover_color.with(Option::is_some);
+        let Some(viewer) = viewer_context.viewer() else {
+            return;
+        };
+
+        destroy_handler(handler);
+        let screen_space_handler = ScreenSpaceEventHandler::new(&viewer.scene().canvas());
+
+        let click = Closure::wrap(Box::new(move |event: PositionedEvent| {
+            viewer_context.with_viewer(|viewer: Viewer| {
+                match pick(&viewer, event.position()) {
+                    Some(feature) => {
+                        viewer_context.set_selected_feature(Some(feature.clone()));
+                        if let Some(on_click) = on_click {
+                            on_click.run(feature);
+                        }
+                    }
+                    // A feature of another tileset is selected by that tileset's handler
+                    None => {
+                        let picked = viewer.scene().pick(&event.position());
+                        if !picked.is_instance_of::<Cesium3DTileFeature>() {
+                            viewer_context.clear_selected_feature();
+                        }
+                    }
+                }
+            });
+        }) as Box<dyn FnMut(PositionedEvent)>)
+        .into_js_value()
+        .unchecked_into::<js_sys::Function>();
+        screen_space_handler.set_input_action(&click, ScreenSpaceEventType::LeftClick);
+
+        if hover_enabled {
+            let hover = Closure::wrap(Box::new(move |event: MotionEvent| {
+                let feature = viewer_context
+                    .with_viewer(|viewer: Viewer| pick(&viewer, event.end_position()))
+                    .flatten();
+                let unchanged = hovered.with_value(|hovered| {
+                    let current = hovered.as_ref().map(|(feature, _)| JsValue::from(feature));
+                    current == feature.as_ref().map(JsValue::from)
+                });
+                if unchanged {
+                    return;
+                }
+
+                restore_hovered(hovered);
+                if let Some(feature) = &feature {
+                    hovered.set_value(Some((feature.clone(), feature.color())));
+                    if let Some(color) = hover_color.get_untracked() {
+                        feature.set_color(&color);
+                    }
+                }
+                viewer_context.request_render();
+                if let Some(on_hover) = on_hover {
+                    on_hover.run(feature);
+                }
+            }) as Box<dyn FnMut(MotionEvent)>)
+            .into_js_value()
+            .unchecked_into::<js_sys::Function>();
+            screen_space_handler.set_input_action(&hover, ScreenSpaceEventType::MouseMove);
+        }
+
+        handler.set_value(Some(screen_space_handler));
+    });
+
+    on_cleanup(move || {
+        restore_hovered(hovered);
+        viewer_context.request_render();
+        destroy_handler(handler);
+
+        let tileset = tileset.try_get_untracked().flatten();
+        let selected = viewer_context.selected_feature_untracked();
+        if let (Some(tileset), Some(selected)) = (tileset, selected)
+            && selected.belongs_to(&tileset)
+        {
+            viewer_context.clear_selected_feature();
+        }
+    });
+}
+
+#[cfg(target_arch = "wasm32")]
+fn restore_hovered(hovered: JsStoredValue<Option<(Cesium3DTileFeature, Color)>>) {
+    if let Some((feature, color)) = hovered.try_update_value(Option::take).flatten() {
+        // The feature's tile may have been unloaded in the meantime
+        let _ = feature.try_set_color(&color);
+    }
+}
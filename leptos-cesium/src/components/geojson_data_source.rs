@@ -2,7 +2,8 @@
 
 use leptos::prelude::*;
 
-use crate::bindings::Color;
+use crate::bindings::{ClusterOptions, Color};
+use crate::components::{CesiumDataSourceContext, ClusterCallback, bind_clustering};
 use crate::core::JsSignal;
 
 #[cfg(target_arch = "wasm32")]
@@ -12,7 +13,7 @@ use crate::bindings::{Viewer, geojson_data_source_load, geojson_data_source_load
 #[cfg(target_arch = "wasm32")]
 use crate::components::use_cesium_context;
 #[cfg(target_arch = "wasm32")]
-use wasm_bindgen::JsValue;
+use wasm_bindgen::{JsCast, JsValue};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_futures::JsFuture;
 
@@ -69,6 +70,24 @@ use wasm_bindgen_futures::JsFuture;
 ///     </ViewerContainer>
 /// }
 /// ```
+///
+/// # Clustering Example
+///
+/// ```rust,ignore
+/// let label_cluster = move |entities: Vec<CesiumEntity>, cluster: Cluster| {
+///     cluster.label().set_text(&format!("{} cities", entities.len()));
+/// };
+///
+/// view! {
+///     <ViewerContainer ion_token=token>
+///         <GeoJsonDataSource
+///             url="data/cities.geojson"
+///             clustering=Some(ClusterOptions::new().pixel_range(40.0).expand_on_click(true))
+///             on_cluster=label_cluster
+///         />
+///     </ViewerContainer>
+/// }
+/// ```
 #[component(transparent)]
 pub fn GeoJsonDataSource(
     /// URL to the GeoJSON or TopoJSON file
@@ -110,7 +129,19 @@ pub fn GeoJsonDataSource(
     /// Credit/attribution for the data
     #[prop(optional, into)]
     credit: Signal<Option<String>>,
+
+    /// Entity clustering options; `None` disables clustering
+    #[prop(optional, into)]
+    clustering: Signal<Option<ClusterOptions>>,
+
+    /// Called for every new cluster to restyle its billboard, label and point
+    #[prop(optional, into)]
+    on_cluster: Option<ClusterCallback>,
 ) -> impl IntoView {
+    // Holds the most recently loaded data source
+    let data_source_context = CesiumDataSourceContext::new();
+    bind_clustering(data_source_context, clustering, on_cluster);
+
     #[cfg(target_arch = "wasm32")]
     {
         let viewer_context =
@@ -175,11 +206,15 @@ pub fn GeoJsonDataSource(
                 // Handle the promise
                 wasm_bindgen_futures::spawn_local(async move {
                     match JsFuture::from(add_promise).await {
-                        Ok(_data_source_js) => {
+                        Ok(data_source_js) => {
                             web_sys::console::log_1(&JsValue::from_str(&format!(
                                 "Successfully loaded GeoJSON from {}",
                                 url
                             )));
+                            data_source_context.set_data_source(
+                                data_source_js.unchecked_into::<crate::bindings::DataSource>(),
+                            );
+                            viewer_context.request_render();
                         }
                         Err(e) => {
                             web_sys::console::error_1(&JsValue::from_str(&format!(
@@ -199,7 +234,9 @@ pub fn GeoJsonDataSource(
                 viewer_ctx.with_viewer(|viewer: Viewer| {
                     viewer.data_sources().remove_all();
                 });
+                viewer_ctx.request_render();
             }
+            data_source_context.clear_data_source();
         });
     }
 
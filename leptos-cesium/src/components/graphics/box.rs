@@ -5,7 +5,7 @@ use crate::core::JsSignal;
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_entity_context;
+use crate::components::{use_cesium_context, use_entity_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -39,6 +39,8 @@ pub fn BoxGraphics(
     #[cfg(target_arch = "wasm32")]
     {
         let entity_context = use_entity_context().expect("BoxGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("BoxGraphics must be inside ViewerContainer");
 
         Effect::new(move |_| {
             entity_context.with_entity(|entity| {
@@ -108,6 +110,7 @@ pub fn BoxGraphics(
                 // Set the box property on the entity
                 let _ = Reflect::set(&entity, &JsValue::from_str("box"), &box_options);
             });
+            viewer_context.request_render();
         });
     }
 
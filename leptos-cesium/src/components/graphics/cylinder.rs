@@ -5,7 +5,7 @@ use crate::core::JsSignal;
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_entity_context;
+use crate::components::{use_cesium_context, use_entity_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -52,6 +52,8 @@ pub fn CylinderGraphics(
     {
         let entity_context =
             use_entity_context().expect("CylinderGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("CylinderGraphics must be inside ViewerContainer");
 
         Effect::new(move |_| {
             entity_context.with_entity(|entity| {
@@ -153,6 +155,7 @@ pub fn CylinderGraphics(
                 // Set the cylinder property on the entity
                 let _ = Reflect::set(&entity, &JsValue::from_str("cylinder"), &cylinder_options);
             });
+            viewer_context.request_render();
         });
     }
 
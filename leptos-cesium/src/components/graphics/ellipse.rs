@@ -5,7 +5,7 @@ use crate::core::JsSignal;
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_entity_context;
+use crate::components::{use_cesium_context, use_entity_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -49,6 +49,8 @@ pub fn EllipseGraphics(
     {
         let entity_context =
             use_entity_context().expect("EllipseGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("EllipseGraphics must be inside ViewerContainer");
 
         Effect::new(move |_| {
             entity_context.with_entity(|entity| {
@@ -141,6 +143,7 @@ pub fn EllipseGraphics(
                 // Set the ellipse property on the entity
                 let _ = Reflect::set(&entity, &JsValue::from_str("ellipse"), &ellipse_options);
             });
+            viewer_context.request_render();
         });
     }
 
@@ -5,7 +5,7 @@ use crate::core::JsSignal;
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_entity_context;
+use crate::components::{use_cesium_context, use_entity_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -49,6 +49,8 @@ pub fn EllipsoidGraphics(
     {
         let entity_context =
             use_entity_context().expect("EllipsoidGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("EllipsoidGraphics must be inside ViewerContainer");
 
         Effect::new(move |_| {
             entity_context.with_entity(|entity| {
@@ -145,6 +147,7 @@ pub fn EllipsoidGraphics(
                 // Set the ellipsoid property on the entity
                 let _ = Reflect::set(&entity, &JsValue::from_str("ellipsoid"), &ellipsoid_options);
             });
+            viewer_context.request_render();
         });
     }
 
@@ -5,6 +5,7 @@ pub mod corridor;
 pub mod cylinder;
 pub mod ellipse;
 pub mod ellipsoid;
+pub mod model;
 pub mod point;
 pub mod polygon;
 pub mod polyline;
@@ -17,6 +18,7 @@ pub use corridor::*;
 pub use cylinder::*;
 pub use ellipse::*;
 pub use ellipsoid::*;
+pub use model::*;
 pub use point::*;
 pub use polygon::*;
 pub use polyline::*;
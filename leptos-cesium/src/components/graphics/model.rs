@@ -0,0 +1,116 @@
+//! ModelGraphics component
+
+use crate::bindings::{ClippingPlanes, Color};
+use crate::core::JsSignal;
+use leptos::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use crate::components::{use_cesium_context, use_entity_context};
+#[cfg(target_arch = "wasm32")]
+use js_sys::Reflect;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::JsValue;
+
+/// ModelGraphics component for displaying a glTF/GLB model at an entity's position
+#[component(transparent)]
+pub fn ModelGraphics(
+    /// URI of the glTF or GLB model
+    #[prop(into)]
+    uri: Signal<String>,
+    /// Uniform scale applied to the model
+    #[prop(optional, into)]
+    scale: Signal<Option<f64>>,
+    /// Approximate minimum size of the model in pixels regardless of zoom
+    #[prop(optional, into)]
+    minimum_pixel_size: Signal<Option<f64>>,
+    /// Upper limit on the scale when `minimum_pixel_size` enlarges the model
+    #[prop(optional, into)]
+    maximum_scale: Signal<Option<f64>>,
+    /// Color blended with the model's own colors
+    #[prop(optional, into)]
+    color: JsSignal<Option<Color>>,
+    /// Clipping planes cutting into the model, relative to its position
+    #[prop(optional, into)]
+    clipping: Signal<Option<ClippingPlanes>>,
+    /// Show the model
+    #[prop(optional, into)]
+    show: Signal<Option<bool>>,
+) -> impl IntoView {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let entity_context = use_entity_context().expect("ModelGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("ModelGraphics must be inside ViewerContainer");
+
+        // Update the entity's model graphics in place, so the clipping set below survives
+        Effect::new(move |_| {
+            let uri = uri.get();
+            let scale = scale.get();
+            let minimum_pixel_size = minimum_pixel_size.get();
+            let maximum_scale = maximum_scale.get();
+            let color = color.get();
+            let show = show.get();
+
+            entity_context.with_entity(|entity: JsValue| {
+                let model = model_graphics(&entity);
+                let set = |key: &str, value: JsValue| {
+                    let _ = Reflect::set(&model, &JsValue::from_str(key), &value);
+                };
+
+                set("uri", JsValue::from_str(&uri));
+                set("scale", scale.map_or(JsValue::UNDEFINED, JsValue::from_f64));
+                set(
+                    "minimumPixelSize",
+                    minimum_pixel_size.map_or(JsValue::UNDEFINED, JsValue::from_f64),
+                );
+                set(
+                    "maximumScale",
+                    maximum_scale.map_or(JsValue::UNDEFINED, JsValue::from_f64),
+                );
+                set("color", color.map_or(JsValue::UNDEFINED, JsValue::from));
+                set("show", show.map_or(JsValue::UNDEFINED, JsValue::from_bool));
+            });
+            viewer_context.request_render();
+        });
+
+        // Clipping collections are owned by one model, so build a fresh one for each change
+        Effect::new(move |_| {
+            let clipping = clipping.get();
+            entity_context.with_entity(|entity: JsValue| {
+                let planes = clipping.map_or(JsValue::UNDEFINED, |planes| planes.build().into());
+                let _ = Reflect::set(
+                    &model_graphics(&entity),
+                    &JsValue::from_str("clippingPlanes"),
+                    &planes,
+                );
+            });
+            viewer_context.request_render();
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (
+            uri,
+            scale,
+            minimum_pixel_size,
+            maximum_scale,
+            color,
+            clipping,
+            show,
+        );
+    }
+}
+
+/// The entity's model graphics, created empty if it has none yet
+#[cfg(target_arch = "wasm32")]
+fn model_graphics(entity: &JsValue) -> JsValue {
+    let key = JsValue::from_str("model");
+    let model = Reflect::get(entity, &key).unwrap_or(JsValue::UNDEFINED);
+    if !model.is_undefined() && !model.is_null() {
+        return model;
+    }
+    // Cesium turns the options object into a ModelGraphics
+    let _ = Reflect::set(entity, &key, &js_sys::Object::new());
+    Reflect::get(entity, &key).unwrap_or(JsValue::UNDEFINED)
+}
@@ -5,7 +5,7 @@ use crate::core::JsSignal;
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_entity_context;
+use crate::components::{use_cesium_context, use_entity_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -42,6 +42,8 @@ pub fn PointGraphics(
     #[cfg(target_arch = "wasm32")]
     {
         let entity_context = use_entity_context().expect("PointGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("PointGraphics must be inside ViewerContainer");
 
         Effect::new(move |_| {
             entity_context.with_entity(|entity| {
@@ -120,6 +122,7 @@ pub fn PointGraphics(
                 // Set the point property on the entity
                 let _ = Reflect::set(&entity, &JsValue::from_str("point"), &point_options);
             });
+            viewer_context.request_render();
         });
     }
 
@@ -5,7 +5,7 @@ use crate::core::JsSignal;
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_entity_context;
+use crate::components::{use_cesium_context, use_entity_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -40,6 +40,8 @@ pub fn PolygonGraphics(
     {
         let entity_context =
             use_entity_context().expect("PolygonGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("PolygonGraphics must be inside ViewerContainer");
 
         Effect::new(move |_| {
             entity_context.with_entity(|entity| {
@@ -109,6 +111,7 @@ pub fn PolygonGraphics(
                 // Set the polygon property on the entity
                 let _ = Reflect::set(&entity, &JsValue::from_str("polygon"), &polygon_options);
             });
+            viewer_context.request_render();
         });
     }
 
@@ -5,7 +5,7 @@ use crate::core::JsSignal;
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_entity_context;
+use crate::components::{use_cesium_context, use_entity_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Array, Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -46,6 +46,8 @@ pub fn PolylineGraphics(
     {
         let entity_context =
             use_entity_context().expect("PolylineGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("PolylineGraphics must be inside ViewerContainer");
 
         Effect::new(move |_| {
             entity_context.with_entity(|entity| {
@@ -122,6 +124,7 @@ pub fn PolylineGraphics(
                 // Set the polyline property on the entity
                 let _ = Reflect::set(&entity, &JsValue::from_str("polyline"), &polyline_options);
             });
+            viewer_context.request_render();
         });
     }
 
@@ -5,7 +5,7 @@ use crate::core::JsSignal;
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_entity_context;
+use crate::components::{use_cesium_context, use_entity_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Array, Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -52,6 +52,8 @@ pub fn PolylineVolumeGraphics(
     {
         let entity_context =
             use_entity_context().expect("PolylineVolumeGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("PolylineVolumeGraphics must be inside ViewerContainer");
 
         Effect::new(move |_| {
             entity_context.with_entity(|entity| {
@@ -150,6 +152,7 @@ pub fn PolylineVolumeGraphics(
                     &polyline_volume_options,
                 );
             });
+            viewer_context.request_render();
         });
     }
 
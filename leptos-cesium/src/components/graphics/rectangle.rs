@@ -5,7 +5,7 @@ use crate::core::JsSignal;
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_entity_context;
+use crate::components::{use_cesium_context, use_entity_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -46,6 +46,8 @@ pub fn RectangleGraphics(
     {
         let entity_context =
             use_entity_context().expect("RectangleGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("RectangleGraphics must be inside ViewerContainer");
 
         Effect::new(move |_| {
             entity_context.with_entity(|entity| {
@@ -133,6 +135,7 @@ pub fn RectangleGraphics(
                 // Set the rectangle property on the entity
                 let _ = Reflect::set(&entity, &JsValue::from_str("rectangle"), &rectangle_options);
             });
+            viewer_context.request_render();
         });
     }
 
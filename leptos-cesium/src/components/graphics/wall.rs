@@ -5,7 +5,7 @@ use crate::core::JsSignal;
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_entity_context;
+use crate::components::{use_cesium_context, use_entity_context};
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Array, Object, Reflect};
 #[cfg(target_arch = "wasm32")]
@@ -51,6 +51,8 @@ pub fn WallGraphics(
     #[cfg(target_arch = "wasm32")]
     {
         let entity_context = use_entity_context().expect("WallGraphics must be a child of Entity");
+        let viewer_context =
+            use_cesium_context().expect("WallGraphics must be inside ViewerContainer");
 
         Effect::new(move |_| {
             entity_context.with_entity(|entity| {
@@ -147,6 +149,7 @@ pub fn WallGraphics(
                 // Set the wall property on the entity
                 let _ = Reflect::set(&entity, &JsValue::from_str("wall"), &wall_options);
             });
+            viewer_context.request_render();
         });
     }
 
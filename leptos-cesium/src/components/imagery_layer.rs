@@ -0,0 +1,236 @@
+//! Imagery layer component for adding imagery providers to the globe
+
+use leptos::prelude::*;
+
+use crate::bindings::{ImageryProvider, SplitDirection};
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{Event, EventListener, ImageryLayer as CesiumImageryLayer, Viewer};
+#[cfg(target_arch = "wasm32")]
+use crate::components::use_cesium_context;
+#[cfg(target_arch = "wasm32")]
+use crate::core::{CesiumError, JsRwSignal, JsStoredValue};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+#[cfg(target_arch = "wasm32")]
+use web_sys::console;
+
+// Cesium's `ImageryLayer.DEFAULT_*` values, restored when a display prop is reset to `None`
+#[cfg(target_arch = "wasm32")]
+const DEFAULT_ALPHA: f64 = 1.0;
+#[cfg(target_arch = "wasm32")]
+const DEFAULT_BRIGHTNESS: f64 = 1.0;
+#[cfg(target_arch = "wasm32")]
+const DEFAULT_CONTRAST: f64 = 1.0;
+#[cfg(target_arch = "wasm32")]
+const DEFAULT_HUE: f64 = 0.0;
+#[cfg(target_arch = "wasm32")]
+const DEFAULT_SATURATION: f64 = 1.0;
+#[cfg(target_arch = "wasm32")]
+const DEFAULT_GAMMA: f64 = 1.0;
+
+/// Listener reporting the errors of a layer or of its provider
+#[cfg(target_arch = "wasm32")]
+type ErrorListener = EventListener<dyn FnMut(JsValue)>;
+
+/// Imagery layer component for declaratively adding imagery to the globe
+///
+/// Layers are added to `viewer.imageryLayers` above the base layer in the order the components
+/// are created, so later layers draw on top of earlier ones. A layer mounted later, for example
+/// inside `<Show>`, goes on top of the layers already mounted. Changing `provider` replaces the
+/// layer in place and the layer is removed on unmount.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let (imagery_error, set_imagery_error) = signal(None::<String>);
+///
+/// view! {
+///     <ViewerContainer ion_token=token>
+///         <ImageryLayer provider=ImageryProvider::OpenStreetMap { url: None } />
+///         <ImageryLayer
+///             provider=ImageryProvider::WebMapService {
+///                 url: "https://example.com/wms".to_string(),
+///                 layers: "precipitation".to_string(),
+///                 parameters: vec![("transparent".into(), "true".into()), ("format".into(), "image/png".into())],
+///             }
+///             alpha=0.6
+///             split_direction=SplitDirection::Right
+///             error=set_imagery_error
+///         />
+///     </ViewerContainer>
+/// }
+/// ```
+#[component]
+pub fn ImageryLayer(
+    /// Source of the imagery tiles
+    #[prop(into)]
+    provider: Signal<ImageryProvider>,
+    /// Opacity from 0.0 (transparent) to 1.0 (opaque)
+    #[prop(optional, into)]
+    alpha: Signal<Option<f64>>,
+    /// Brightness; 1.0 uses the unmodified imagery color
+    #[prop(optional, into)]
+    brightness: Signal<Option<f64>>,
+    /// Contrast; 1.0 uses the unmodified imagery color
+    #[prop(optional, into)]
+    contrast: Signal<Option<f64>>,
+    /// Hue shift in radians; 0.0 uses the unmodified imagery color
+    #[prop(optional, into)]
+    hue: Signal<Option<f64>>,
+    /// Saturation; 1.0 uses the unmodified imagery color
+    #[prop(optional, into)]
+    saturation: Signal<Option<f64>>,
+    /// Gamma correction; 1.0 uses the unmodified imagery color
+    #[prop(optional, into)]
+    gamma: Signal<Option<f64>>,
+    /// Whether to show the layer (default: true)
+    #[prop(optional, into, default = true.into())]
+    show: Signal<bool>,
+    /// Which side of the viewer's splitter the layer is shown on (default: both)
+    #[prop(optional, into)]
+    split_direction: Signal<SplitDirection>,
+    /// Receives the error message when the provider cannot be created or a tile request fails,
+    /// and `None` once the provider is ready
+    #[prop(optional)]
+    error: Option<WriteSignal<Option<String>>>,
+) -> impl IntoView {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context =
+            use_cesium_context().expect("ImageryLayer must be inside ViewerContainer");
+        let position = viewer_context.register_imagery_layer();
+        let layer: JsRwSignal<Option<CesiumImageryLayer>> = RwSignal::new_local(None);
+        let listeners: JsStoredValue<Vec<ErrorListener>> = StoredValue::new_local(Vec::new());
+
+        // Create the layer at its position, replacing the previous one when the provider changes
+        Effect::new(move |_| {
+            let provider = provider.get();
+            let Some(viewer) = viewer_context.viewer() else {
+                return;
+            };
+
+            let layers = viewer.imagery_layers();
+            listeners.set_value(Vec::new());
+            if let Some(previous) = layer.get_untracked() {
+                layers.remove(&previous, true);
+            }
+
+            let new_layer =
+                CesiumImageryLayer::from_provider_async(&provider.create(), &JsValue::UNDEFINED);
+            layers.add_at(
+                &new_layer,
+                viewer_context.imagery_layer_index(position, &layers),
+            );
+            viewer_context.set_imagery_layer(position, Some(new_layer.clone()));
+            console::debug_1(&JsValue::from_str("ImageryLayer: layer added"));
+            watch_errors(&new_layer, error, listeners);
+            layer.set(Some(new_layer));
+            viewer_context.request_render();
+        });
+
+        // Keep display properties in sync without recreating the layer
+        Effect::new(move |_| {
+            let alpha = alpha.get();
+            let brightness = brightness.get();
+            let contrast = contrast.get();
+            let hue = hue.get();
+            let saturation = saturation.get();
+            let gamma = gamma.get();
+            let show = show.get();
+            let split_direction = split_direction.get();
+
+            layer.with(|layer| {
+                let Some(layer) = layer else {
+                    return;
+                };
+                layer.set_alpha(alpha.unwrap_or(DEFAULT_ALPHA));
+                layer.set_brightness(brightness.unwrap_or(DEFAULT_BRIGHTNESS));
+                layer.set_contrast(contrast.unwrap_or(DEFAULT_CONTRAST));
+                layer.set_hue(hue.unwrap_or(DEFAULT_HUE));
+                layer.set_saturation(saturation.unwrap_or(DEFAULT_SATURATION));
+                layer.set_gamma(gamma.unwrap_or(DEFAULT_GAMMA));
+                layer.set_show(show);
+                layer.set_split_direction(split_direction);
+            });
+            viewer_context.request_render();
+        });
+
+        on_cleanup(move || {
+            listeners.try_update_value(Vec::clear);
+            viewer_context.set_imagery_layer(position, None);
+            if let Some(layer) = layer.try_get_untracked().flatten() {
+                viewer_context.with_viewer(|viewer: Viewer| {
+                    viewer.imagery_layers().remove(&layer, true);
+                    console::debug_1(&JsValue::from_str("ImageryLayer: layer removed"));
+                });
+                viewer_context.request_render();
+            }
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (
+            provider,
+            alpha,
+            brightness,
+            contrast,
+            hue,
+            saturation,
+            gamma,
+            show,
+            split_direction,
+            error,
+        );
+    }
+}
+
+/// Report the errors of a layer and, once it is ready, of its provider through listeners kept
+/// in `listeners`
+#[cfg(target_arch = "wasm32")]
+fn watch_errors(
+    layer: &CesiumImageryLayer,
+    error: Option<WriteSignal<Option<String>>>,
+    listeners: JsStoredValue<Vec<ErrorListener>>,
+) {
+    let report = move |err: &JsValue| {
+        let message = CesiumError::from_js(err).to_string();
+        console::error_1(&JsValue::from_str(&format!("ImageryLayer: {message}")));
+        if let Some(error) = error {
+            error.try_set(Some(message));
+        }
+    };
+
+    let on_error =
+        Closure::wrap(Box::new(move |err: JsValue| report(&err)) as Box<dyn FnMut(JsValue)>);
+    let on_ready = Closure::wrap(Box::new(move |provider: JsValue| {
+        if let Some(event) = provider_error_event(&provider) {
+            // Called with a `TileProviderError`, whose `error` is what the request failed with
+            let on_tile_error = Closure::wrap(Box::new(move |tile_error: JsValue| {
+                let message = js_sys::Reflect::get(&tile_error, &JsValue::from_str("message"))
+                    .unwrap_or(tile_error);
+                report(&message);
+            }) as Box<dyn FnMut(JsValue)>);
+            listeners.try_update_value(|listeners| {
+                listeners.push(EventListener::new(event, on_tile_error));
+            });
+        }
+        if let Some(error) = error {
+            error.try_set(None);
+        }
+    }) as Box<dyn FnMut(JsValue)>);
+
+    listeners.set_value(vec![
+        EventListener::new(layer.error_event(), on_error),
+        EventListener::new(layer.ready_event(), on_ready),
+    ]);
+}
+
+#[cfg(target_arch = "wasm32")]
+fn provider_error_event(provider: &JsValue) -> Option<Event> {
+    js_sys::Reflect::get(provider, &JsValue::from_str("errorEvent"))
+        .ok()
+        .filter(|event| !event.is_undefined() && !event.is_null())
+        .map(JsCast::unchecked_into)
+}
@@ -1,20 +1,42 @@
 //! Leptos component primitives for Cesium.
 
 pub mod camera;
+pub mod camera_controller;
+pub mod camera_tour;
+#[cfg(feature = "router")]
+pub mod camera_url_sync;
+pub mod clustering;
 pub mod context;
+pub mod custom_data_source;
 pub mod czml_data_source;
 pub mod entity;
 pub mod events;
+pub mod feature_picking;
 pub mod geojson_data_source;
 pub mod graphics;
+pub mod imagery_layer;
+pub mod screenshot;
+pub mod selection;
+pub mod terrain;
 pub mod tileset;
 pub mod viewer_container;
 
 pub use camera::*;
+pub use camera_controller::*;
+pub use camera_tour::*;
+#[cfg(feature = "router")]
+pub use camera_url_sync::*;
+pub use clustering::*;
 pub use context::*;
+pub use custom_data_source::*;
 pub use czml_data_source::*;
 pub use entity::*;
+pub use feature_picking::*;
 pub use geojson_data_source::*;
 pub use graphics::*;
+pub use imagery_layer::*;
+pub use screenshot::*;
+pub use selection::*;
+pub use terrain::*;
 pub use tileset::*;
 pub use viewer_container::*;
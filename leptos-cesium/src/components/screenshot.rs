@@ -0,0 +1,112 @@
+//! Screenshot button capturing the current view
+
+use leptos::prelude::*;
+
+use crate::bindings::ScreenshotOptions;
+use crate::core::CesiumError;
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::capture_screenshot;
+#[cfg(target_arch = "wasm32")]
+use crate::components::use_cesium_context;
+
+/// Button that captures the current view and hands the encoded image to `on_capture`
+///
+/// The button is disabled while a capture is in progress. Place it inside `ViewerContainer`
+/// and position it with `class` or `style`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let (snapshot, set_snapshot) = signal(None::<Vec<u8>>);
+///
+/// view! {
+///     <ViewerContainer>
+///         <ScreenshotButton
+///             options=ScreenshotOptions::new().resolution_scale(2.0)
+///             on_capture=move |bytes| set_snapshot.set(Some(bytes))
+///             style="position: absolute; top: 8px; left: 8px;"
+///         >
+///             "Add to report"
+///         </ScreenshotButton>
+///     </ViewerContainer>
+/// }
+/// ```
+#[component]
+pub fn ScreenshotButton(
+    /// Called with the encoded image bytes
+    #[prop(into)]
+    on_capture: UnsyncCallback<Vec<u8>>,
+    /// Called when the capture fails
+    #[prop(optional, into)]
+    on_error: Option<UnsyncCallback<CesiumError>>,
+    /// Image format and resolution (default: PNG at the current resolution)
+    #[prop(optional, into)]
+    options: Signal<ScreenshotOptions>,
+    /// CSS class of the button
+    #[prop(optional, into)]
+    class: String,
+    /// Inline styles of the button
+    #[prop(optional, into)]
+    style: String,
+    /// Button content (default: "Screenshot")
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let capturing = RwSignal::new(false);
+
+    #[cfg(target_arch = "wasm32")]
+    let viewer_context =
+        use_cesium_context().expect("ScreenshotButton must be inside ViewerContainer");
+
+    let on_click = move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let Some(viewer) = viewer_context.viewer_untracked() else {
+                if let Some(on_error) = on_error {
+                    on_error.run(CesiumError::ViewerUnavailable);
+                }
+                return;
+            };
+            capturing.set(true);
+            let options = options.get_untracked();
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = capture_screenshot(&viewer, options).await;
+                if capturing.try_set(false).is_some() {
+                    // Unmounted while capturing
+                    return;
+                }
+                match result {
+                    Ok(bytes) => {
+                        on_capture.try_run(bytes);
+                    }
+                    Err(error) => {
+                        if let Some(on_error) = on_error {
+                            on_error.try_run(error);
+                        }
+                    }
+                }
+            });
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = (on_capture, on_error, options);
+        }
+    };
+
+    view! {
+        <button
+            type="button"
+            class=class
+            style=style
+            disabled=move || capturing.get()
+            on:click=on_click
+        >
+            {match children {
+                Some(children) => children(),
+                None => "Screenshot".into_any(),
+            }}
+        </button>
+    }
+}
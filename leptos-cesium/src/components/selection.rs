@@ -0,0 +1,475 @@
+//! Multi-selection of entities with modifier clicks, box and lasso selection
+
+use leptos::prelude::*;
+
+use crate::bindings::{KeyboardEventModifier, SelectionStyle};
+
+#[cfg(target_arch = "wasm32")]
+use std::collections::HashMap;
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{
+    Cartesian2, Highlight, PickResult, PositionedEvent, ScreenSpaceEventHandler,
+    ScreenSpaceEventType, Viewer, pick, pick_entities_in_polygon, pick_entities_in_rectangle,
+};
+#[cfg(target_arch = "wasm32")]
+use crate::components::{CesiumViewerContext, use_cesium_context};
+#[cfg(target_arch = "wasm32")]
+use crate::core::JsStoredValue;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, closure::Closure};
+#[cfg(target_arch = "wasm32")]
+use web_sys::{HtmlCanvasElement, MouseEvent, PointerEvent};
+
+/// Drags shorter than this many pixels in both directions count as clicks, matching the
+/// default `clickPixelTolerance` of Cesium's event handler
+#[cfg(target_arch = "wasm32")]
+const MINIMUM_DRAG_SIZE: f64 = 5.0;
+
+#[cfg(target_arch = "wasm32")]
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// Shape dragged out to select entities
+#[cfg(target_arch = "wasm32")]
+#[derive(Clone, Copy, PartialEq)]
+enum DragShape {
+    Box,
+    Lasso,
+}
+
+/// A drag in progress
+#[cfg(target_arch = "wasm32")]
+struct Drag {
+    shape: DragShape,
+    /// Modifier key the drag was started with
+    modifier: KeyboardEventModifier,
+    pointer_id: i32,
+    /// Start and current position of a box, or every position along a lasso
+    path: Vec<(f64, f64)>,
+    /// SVG covering the canvas, and the polygon drawn in it
+    overlay: web_sys::Element,
+    outline: web_sys::Element,
+    /// The camera's `enableInputs` before the drag
+    enable_inputs: bool,
+}
+
+/// DOM event type and the listener registered for it
+#[cfg(target_arch = "wasm32")]
+type PointerListener = (&'static str, Closure<dyn FnMut(PointerEvent)>);
+
+/// Click handler and pointer listeners bound to the canvas, with the closures they call
+#[cfg(target_arch = "wasm32")]
+struct Bindings {
+    handler: ScreenSpaceEventHandler,
+    _clicks: Vec<Closure<dyn FnMut(PositionedEvent)>>,
+    canvas: HtmlCanvasElement,
+    pointer_listeners: Vec<PointerListener>,
+}
+
+/// Selects entities with the mouse and highlights the selection
+///
+/// Keeps the viewer context's multi-selection (`select`, `toggle`, `is_selected`, ...) in sync
+/// with the mouse:
+///
+/// - a click selects the entity under the pointer, or clears the selection on empty space
+/// - a Ctrl-click toggles the entity under the pointer
+/// - a Shift-click adds the entity under the pointer
+/// - dragging while holding `box_modifier` selects every entity whose position is inside the
+///   dragged rectangle
+/// - dragging while holding `lasso_modifier` selects every entity whose position is inside the
+///   drawn outline
+/// - a box or lasso replaces the selection, unless Ctrl is also held when releasing the mouse,
+///   which adds to it (Shift when Ctrl is the drag's modifier)
+///
+/// Entities behind the globe are never caught by a box or lasso. Selected entities are
+/// highlighted with `style`; the selection can also be changed from code through the context,
+/// and is highlighted the same way.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let viewer_context = use_cesium_context().unwrap();
+///
+/// view! {
+///     <ViewerContainer>
+///         <EntitySelection style=SelectionStyle::new().color(Color::cyan()) />
+///         <Entity name="Depot".to_string() position=depot>
+///             <PointGraphics pixel_size=10.0 />
+///         </Entity>
+///     </ViewerContainer>
+///     <p>{move || format!("{} selected", viewer_context.selected_ids().len())}</p>
+/// }
+/// ```
+#[component(transparent)]
+pub fn EntitySelection(
+    /// How selected entities are highlighted (default: yellow)
+    #[prop(optional, into)]
+    style: Signal<SelectionStyle>,
+    /// Modifier key that turns a drag into box selection, `None` disables it (default: Shift)
+    #[prop(default = Signal::stored(Some(KeyboardEventModifier::Shift)), into)]
+    box_modifier: Signal<Option<KeyboardEventModifier>>,
+    /// Modifier key that turns a drag into lasso selection, `None` disables it (default: Alt).
+    /// Box selection wins when both use the same key.
+    #[prop(default = Signal::stored(Some(KeyboardEventModifier::Alt)), into)]
+    lasso_modifier: Signal<Option<KeyboardEventModifier>>,
+) -> impl IntoView {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context =
+            use_cesium_context().expect("EntitySelection must be inside ViewerContainer");
+        let bindings: JsStoredValue<Option<Bindings>> = StoredValue::new_local(None);
+        let drag: JsStoredValue<Option<Drag>> = StoredValue::new_local(None);
+        let highlights: JsStoredValue<HashMap<String, Highlight>> =
+            StoredValue::new_local(HashMap::new());
+        // Style the current highlights were applied with
+        let applied_style = StoredValue::new(style.get_untracked());
+
+        Effect::new(move |_| {
+            let Some(viewer) = viewer_context.viewer() else {
+                return;
+            };
+
+            end_drag(viewer_context, drag);
+            unbind(bindings);
+            let canvas = viewer.scene().canvas();
+            let handler = ScreenSpaceEventHandler::new(&canvas);
+
+            let click = |modifier: Option<KeyboardEventModifier>| {
+                Closure::wrap(Box::new(move |event: PositionedEvent| {
+                    let id = viewer_context
+                        .with_viewer(|viewer: Viewer| {
+                            pick(&viewer, &event.position())
+                                .as_ref()
+                                .and_then(PickResult::entity)
+                                .map(|entity| entity.id())
+                        })
+                        .flatten();
+                    match (modifier, id) {
+                        (Some(KeyboardEventModifier::Ctrl), Some(id)) => viewer_context.toggle(id),
+                        (Some(KeyboardEventModifier::Shift), Some(id)) => viewer_context.select(id),
+                        (None, Some(id)) => viewer_context.set_selection([id]),
+                        (None, None) => viewer_context.clear_selection(),
+                        _ => {}
+                    }
+                }) as Box<dyn FnMut(PositionedEvent)>)
+            };
+            let mut clicks = Vec::new();
+            for modifier in [
+                None,
+                Some(KeyboardEventModifier::Ctrl),
+                Some(KeyboardEventModifier::Shift),
+            ] {
+                let closure = click(modifier);
+                let action = closure.as_ref().unchecked_ref();
+                match modifier {
+                    Some(modifier) => handler.set_input_action_with_modifier(
+                        action,
+                        ScreenSpaceEventType::LeftClick,
+                        modifier,
+                    ),
+                    None => handler.set_input_action(action, ScreenSpaceEventType::LeftClick),
+                }
+                clicks.push(closure);
+            }
+
+            let pointer_listeners =
+                bind_drag_selection(viewer_context, &canvas, box_modifier, lasso_modifier, drag);
+            bindings.set_value(Some(Bindings {
+                handler,
+                _clicks: clicks,
+                canvas,
+                pointer_listeners,
+            }));
+        });
+
+        Effect::new(move |_| {
+            let ids = viewer_context.selected_ids();
+            let style = style.get();
+            let Some(viewer) = viewer_context.viewer() else {
+                return;
+            };
+
+            if applied_style.get_value() != style {
+                restore_highlights(highlights);
+                applied_style.set_value(style);
+            }
+            highlights.update_value(|highlights| {
+                highlights
+                    .extract_if(|id, _| !ids.contains(id))
+                    .for_each(|(_, highlight)| highlight.restore());
+                for id in &ids {
+                    if let Some(entity) = crate::components::camera::find_entity(&viewer, id) {
+                        highlights
+                            .entry(id.clone())
+                            .or_insert_with(|| style.apply(&entity));
+                    }
+                }
+            });
+            viewer_context.request_render();
+        });
+
+        on_cleanup(move || {
+            end_drag(viewer_context, drag);
+            unbind(bindings);
+            restore_highlights(highlights);
+            viewer_context.request_render();
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (style, box_modifier, lasso_modifier);
+    }
+}
+
+/// Listen for box and lasso drags on the canvas
+///
+/// Cesium's event handler reports a single modifier and no pointer id, so drags are read from
+/// the DOM pointer events, which carry every key held. The listeners run in the capture phase
+/// to turn camera input off before Cesium sees the pointer go down.
+#[cfg(target_arch = "wasm32")]
+fn bind_drag_selection(
+    viewer_context: CesiumViewerContext,
+    canvas: &HtmlCanvasElement,
+    box_modifier: Signal<Option<KeyboardEventModifier>>,
+    lasso_modifier: Signal<Option<KeyboardEventModifier>>,
+    drag: JsStoredValue<Option<Drag>>,
+) -> Vec<PointerListener> {
+    let down = move |event: PointerEvent| {
+        if event.button() != 0 {
+            return;
+        }
+        let held = [
+            (DragShape::Box, box_modifier.get_untracked()),
+            (DragShape::Lasso, lasso_modifier.get_untracked()),
+        ]
+        .into_iter()
+        .find_map(|(shape, modifier)| {
+            modifier
+                .filter(|modifier| is_held(&event, *modifier))
+                .map(|modifier| (shape, modifier))
+        });
+        let Some((shape, modifier)) = held else {
+            return;
+        };
+
+        end_drag(viewer_context, drag);
+        viewer_context.with_viewer(|viewer: Viewer| {
+            let canvas = viewer.scene().canvas();
+            let Some((overlay, outline)) = create_overlay(&canvas) else {
+                return;
+            };
+            // Keep the camera still while dragging
+            let controller = viewer.scene().screen_space_camera_controller();
+            let enable_inputs = controller.enable_inputs();
+            controller.set_enable_inputs(false);
+            // Keep receiving the drag when the pointer leaves the canvas
+            let _ = canvas.set_pointer_capture(event.pointer_id());
+            drag.set_value(Some(Drag {
+                shape,
+                modifier,
+                pointer_id: event.pointer_id(),
+                path: vec![position(&event)],
+                overlay,
+                outline,
+                enable_inputs,
+            }));
+        });
+    };
+
+    let moved = move |event: PointerEvent| {
+        drag.update_value(|drag| {
+            let Some(drag) = drag
+                .as_mut()
+                .filter(|drag| drag.pointer_id == event.pointer_id())
+            else {
+                return;
+            };
+            let position = position(&event);
+            if drag.shape == DragShape::Box {
+                drag.path.truncate(1);
+            }
+            drag.path.push(position);
+            let _ = drag
+                .outline
+                .set_attribute("points", &svg_points(&outline_of(drag)));
+        });
+    };
+
+    let up = move |event: PointerEvent| {
+        let is_current = drag.with_value(|drag| {
+            drag.as_ref()
+                .is_some_and(|drag| drag.pointer_id == event.pointer_id())
+        });
+        if !is_current {
+            return;
+        }
+        let Some(finished) = end_drag(viewer_context, drag) else {
+            return;
+        };
+        if event.type_() == "pointercancel" || !is_drag(&finished.path) {
+            // Clicks are left to the click handlers
+            return;
+        }
+
+        let path: Vec<Cartesian2> = finished
+            .path
+            .iter()
+            .map(|&(x, y)| Cartesian2::new(x, y))
+            .collect();
+        let ids: Vec<String> = viewer_context
+            .with_viewer(|viewer: Viewer| {
+                let entities = match finished.shape {
+                    DragShape::Box => {
+                        pick_entities_in_rectangle(&viewer, &path[0], &path[path.len() - 1])
+                    }
+                    DragShape::Lasso => pick_entities_in_polygon(&viewer, &path),
+                };
+                entities.iter().map(|entity| entity.id()).collect()
+            })
+            .unwrap_or_default();
+
+        // The drag's own modifier is held anyway, so another key asks to add
+        let add_key = if finished.modifier == KeyboardEventModifier::Ctrl {
+            KeyboardEventModifier::Shift
+        } else {
+            KeyboardEventModifier::Ctrl
+        };
+        if is_held(&event, add_key) {
+            viewer_context.select_many(ids);
+        } else {
+            viewer_context.set_selection(ids);
+        }
+    };
+
+    let wrap = |listener: Box<dyn FnMut(PointerEvent)>| Closure::wrap(listener);
+    let listeners = vec![
+        ("pointerdown", wrap(Box::new(down))),
+        ("pointermove", wrap(Box::new(moved))),
+        ("pointerup", wrap(Box::new(up))),
+        ("pointercancel", wrap(Box::new(up))),
+    ];
+    for (kind, listener) in &listeners {
+        let _ = canvas.add_event_listener_with_callback_and_bool(
+            kind,
+            listener.as_ref().unchecked_ref(),
+            true,
+        );
+    }
+    listeners
+}
+
+#[cfg(target_arch = "wasm32")]
+fn is_held(event: &MouseEvent, modifier: KeyboardEventModifier) -> bool {
+    match modifier {
+        KeyboardEventModifier::Shift => event.shift_key(),
+        KeyboardEventModifier::Ctrl => event.ctrl_key(),
+        KeyboardEventModifier::Alt => event.alt_key(),
+    }
+}
+
+/// Position of a pointer event in window coordinates of the canvas
+#[cfg(target_arch = "wasm32")]
+fn position(event: &MouseEvent) -> (f64, f64) {
+    (event.offset_x() as f64, event.offset_y() as f64)
+}
+
+/// Whether a path extends far enough to be a drag rather than a click
+#[cfg(target_arch = "wasm32")]
+fn is_drag(path: &[(f64, f64)]) -> bool {
+    let Some(&(start_x, start_y)) = path.first() else {
+        return false;
+    };
+    path.iter().any(|&(x, y)| {
+        (x - start_x).abs() >= MINIMUM_DRAG_SIZE || (y - start_y).abs() >= MINIMUM_DRAG_SIZE
+    })
+}
+
+/// Outline of a drag: the four corners of a box, or the lasso path
+#[cfg(target_arch = "wasm32")]
+fn outline_of(drag: &Drag) -> Vec<(f64, f64)> {
+    match (drag.shape, drag.path.first(), drag.path.last()) {
+        (DragShape::Box, Some(&(x1, y1)), Some(&(x2, y2))) => {
+            vec![(x1, y1), (x2, y1), (x2, y2), (x1, y2)]
+        }
+        _ => drag.path.clone(),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn svg_points(points: &[(f64, f64)]) -> String {
+    points
+        .iter()
+        .map(|(x, y)| format!("{x},{y}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// SVG covering the canvas with a dashed polygon, drawn while dragging
+#[cfg(target_arch = "wasm32")]
+fn create_overlay(canvas: &HtmlCanvasElement) -> Option<(web_sys::Element, web_sys::Element)> {
+    let parent = canvas.parent_element()?;
+    let document = web_sys::window()?.document()?;
+    let overlay = document
+        .create_element_ns(Some(SVG_NAMESPACE), "svg")
+        .ok()?;
+    let outline = document
+        .create_element_ns(Some(SVG_NAMESPACE), "polygon")
+        .ok()?;
+    let _ = overlay.set_attribute(
+        "style",
+        "position: absolute; left: 0; top: 0; width: 100%; height: 100%; pointer-events: none;",
+    );
+    for (name, value) in [
+        ("fill", "rgba(255, 255, 255, 0.15)"),
+        ("stroke", "#fff"),
+        ("stroke-dasharray", "4 2"),
+    ] {
+        let _ = outline.set_attribute(name, value);
+    }
+    overlay.append_child(&outline).ok()?;
+    parent.append_child(&overlay).ok()?;
+    Some((overlay, outline))
+}
+
+/// Remove the overlay of a drag in progress and give the camera its input back
+#[cfg(target_arch = "wasm32")]
+fn end_drag(
+    viewer_context: CesiumViewerContext,
+    drag: JsStoredValue<Option<Drag>>,
+) -> Option<Drag> {
+    let drag = drag.try_update_value(Option::take).flatten()?;
+    drag.overlay.remove();
+    viewer_context.with_viewer(|viewer: Viewer| {
+        viewer
+            .scene()
+            .screen_space_camera_controller()
+            .set_enable_inputs(drag.enable_inputs);
+    });
+    Some(drag)
+}
+
+/// Destroy the click handler and remove the pointer listeners
+#[cfg(target_arch = "wasm32")]
+fn unbind(bindings: JsStoredValue<Option<Bindings>>) {
+    let Some(bindings) = bindings.try_update_value(Option::take).flatten() else {
+        return;
+    };
+    bindings.handler.dispose();
+    for (kind, listener) in &bindings.pointer_listeners {
+        let _ = bindings
+            .canvas
+            .remove_event_listener_with_callback_and_bool(
+                kind,
+                listener.as_ref().unchecked_ref(),
+                true,
+            );
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn restore_highlights(highlights: JsStoredValue<HashMap<String, Highlight>>) {
+    if let Some(highlights) = highlights.try_update_value(std::mem::take) {
+        highlights.into_values().for_each(Highlight::restore);
+    }
+}
@@ -0,0 +1,201 @@
+//! Terrain component for setting the globe's terrain provider
+
+use leptos::prelude::*;
+
+use crate::bindings::{Cartographic, TerrainProvider};
+use crate::core::JsSignal;
+
+#[cfg(target_arch = "wasm32")]
+use crate::bindings::{Viewer, globe_height, sample_terrain_most_detailed};
+#[cfg(target_arch = "wasm32")]
+use crate::components::use_cesium_context;
+#[cfg(target_arch = "wasm32")]
+use crate::core::{CesiumError, JsStoredValue, LoadGeneration};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::JsValue;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_futures::JsFuture;
+#[cfg(target_arch = "wasm32")]
+use web_sys::console;
+
+/// Terrain component for declaratively setting the globe's terrain
+///
+/// Changing `provider` swaps the terrain on the existing viewer once the new provider has
+/// loaded; a load that is overtaken by a later change is discarded. On unmount the globe falls
+/// back to the smooth ellipsoid, unless another provider has been set since.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let (terrain_error, set_terrain_error) = signal(None::<String>);
+///
+/// view! {
+///     <ViewerContainer ion_token=token>
+///         <Terrain
+///             provider=TerrainProvider::CesiumTerrain {
+///                 url: "http://terrain.local/tiles".to_string(),
+///                 request_vertex_normals: true,
+///                 request_water_mask: false,
+///             }
+///             error=set_terrain_error
+///         />
+///     </ViewerContainer>
+///     <Show when=move || terrain_error.get().is_some()>
+///         <p>"Terrain failed to load: " {move || terrain_error.get()}</p>
+///     </Show>
+/// }
+/// ```
+#[component(transparent)]
+pub fn Terrain(
+    /// Source of the terrain heights
+    #[prop(into)]
+    provider: Signal<TerrainProvider>,
+    /// Receives the error message when the provider fails to load, and `None` once a provider
+    /// has loaded successfully
+    #[prop(optional)]
+    error: Option<WriteSignal<Option<String>>>,
+) -> impl IntoView {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context = use_cesium_context().expect("Terrain must be inside ViewerContainer");
+        let generation = LoadGeneration::new();
+        // The provider this component put on the globe
+        let applied: JsStoredValue<Option<JsValue>> = StoredValue::new_local(None);
+
+        Effect::new(move |_| {
+            let provider = provider.get();
+            if viewer_context.viewer().is_none() {
+                return;
+            }
+
+            let current = generation.next();
+
+            let promise = provider.create();
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = JsFuture::from(promise).await;
+                if !generation.is_current(current) {
+                    return;
+                }
+
+                match result {
+                    Ok(terrain_provider) => {
+                        viewer_context.with_viewer(|viewer: Viewer| {
+                            viewer.scene().set_terrain_provider(&terrain_provider);
+                            console::debug_1(&JsValue::from_str("Terrain: provider set"));
+                        });
+                        applied.set_value(Some(terrain_provider));
+                        viewer_context.request_render();
+                        if let Some(error) = error {
+                            error.try_set(None);
+                        }
+                    }
+                    Err(err) => {
+                        console::error_2(&JsValue::from_str("Terrain: failed to load:"), &err);
+                        if let Some(error) = error {
+                            error.try_set(Some(CesiumError::from_js(&err).to_string()));
+                        }
+                    }
+                }
+            });
+        });
+
+        on_cleanup(move || {
+            generation.invalidate();
+            let Some(applied) = applied.try_update_value(Option::take).flatten() else {
+                return;
+            };
+            viewer_context.with_viewer(|viewer: Viewer| {
+                let ellipsoid = TerrainProvider::Ellipsoid.create();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let Ok(ellipsoid) = JsFuture::from(ellipsoid).await else {
+                        return;
+                    };
+                    // Leave the terrain alone when the viewer is gone or another `<Terrain>`
+                    // has set its own provider in the meantime
+                    if viewer.is_destroyed() || viewer.scene().terrain_provider() != applied {
+                        return;
+                    }
+                    let scene = viewer.scene();
+                    scene.set_terrain_provider(&ellipsoid);
+                    scene.request_render();
+                });
+            });
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (provider, error);
+    }
+}
+
+/// Track the terrain height in meters at a position
+///
+/// The height is sampled from the most detailed terrain available whenever `position` changes,
+/// falling back to the height of the globe tiles loaded so far when sampling fails (for
+/// example on the ellipsoid). Resolves to `None` while sampling, when the position is `None`
+/// or when no height is known. Must be called inside `ViewerContainer`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let position = Signal::derive_local(move || {
+///     Some(Cartographic::from_degrees(lon.get(), lat.get(), 0.0))
+/// });
+/// let height = use_terrain_height(position);
+///
+/// view! { <p>"Elevation: " {move || height.get().map(|h| format!("{h:.1} m"))}</p> }
+/// ```
+pub fn use_terrain_height(position: JsSignal<Option<Cartographic>>) -> Signal<Option<f64>> {
+    let height = RwSignal::new(None);
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context =
+            use_cesium_context().expect("use_terrain_height must be used inside ViewerContainer");
+        let generation = LoadGeneration::new();
+
+        Effect::new(move |_| {
+            let position = position.get();
+            let viewer = viewer_context.viewer();
+
+            let current = generation.next();
+            height.set(None);
+
+            let (Some(position), Some(viewer)) = (position, viewer) else {
+                return;
+            };
+
+            let scene = viewer.scene();
+            wasm_bindgen_futures::spawn_local(async move {
+                let sampled = sample_terrain_most_detailed(
+                    &scene.terrain_provider(),
+                    std::slice::from_ref(&position),
+                )
+                .await
+                .and_then(|positions| {
+                    positions
+                        .first()
+                        .map(Cartographic::height)
+                        .ok_or(CesiumError::Js("no height sampled".to_string()))
+                });
+                if !generation.is_current(current) {
+                    return;
+                }
+
+                let sampled = match sampled {
+                    Ok(sampled) => Some(sampled),
+                    Err(_) => globe_height(&scene, &position),
+                };
+                height.try_set(sampled);
+            });
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = position;
+    }
+
+    height.into()
+}
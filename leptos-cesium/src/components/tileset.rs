@@ -2,18 +2,63 @@
 
 use leptos::prelude::*;
 
+use crate::bindings::{Cesium3DTileset, Clipping, Color, Matrix4, ShadowMode, TileStyle};
+use crate::components::{FeatureClickCallback, FeatureHoverCallback};
+use crate::core::JsSignal;
+
 #[cfg(target_arch = "wasm32")]
 use crate::bindings::{
-    Cesium3DTilesetOptions, GooglePhotorealistic3DTilesApiOptions, Viewer,
-    create_google_photorealistic_3d_tileset,
+    Cesium3DTilesetOptions, EventListener, GooglePhotorealistic3DTilesApiOptions, TileFailedError,
+    Viewer, apply_clipping, create_google_photorealistic_3d_tileset,
 };
 #[cfg(target_arch = "wasm32")]
-use crate::components::use_cesium_context;
+use crate::components::{bind_feature_picking, use_cesium_context};
+#[cfg(target_arch = "wasm32")]
+use crate::core::{CesiumError, JsRwSignal, JsStoredValue, LoadGeneration};
 #[cfg(target_arch = "wasm32")]
-use wasm_bindgen::JsValue;
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_futures::JsFuture;
 
+/// Listeners of a tileset's load events, removed when dropped
+#[cfg(target_arch = "wasm32")]
+struct LoadListeners {
+    _progress: EventListener<dyn FnMut(f64, f64)>,
+    _all_loaded: EventListener<dyn FnMut()>,
+    _initial_loaded: EventListener<dyn FnMut()>,
+    _failed: EventListener<dyn FnMut(TileFailedError)>,
+}
+
+/// Cesium's default `maximumScreenSpaceError`, restored when the prop is cleared
+#[cfg(target_arch = "wasm32")]
+const DEFAULT_MAXIMUM_SCREEN_SPACE_ERROR: f64 = 16.0;
+
+/// Loading state of a tileset, updated from its load events
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TilesetLoadState {
+    /// Tile requests waiting for a response
+    pub pending_requests: u32,
+    /// Tiles received and being processed
+    pub tiles_processing: u32,
+    /// Whether the tiles for the initial view have loaded
+    pub initial_tiles_loaded: bool,
+    /// Whether all tiles for the current view have loaded; false again while the view refines
+    pub all_tiles_loaded: bool,
+    /// Number of tiles that failed to load
+    pub failed_tiles: u32,
+    /// The most recent tile that failed to load; use `on_tile_failed` to see every failure
+    pub last_failure: Option<TileFailure>,
+}
+
+/// A tile that failed to load
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileFailure {
+    /// URL of the failed tile
+    pub url: String,
+    /// Error message
+    pub message: String,
+}
+
 /// Component for Google Photorealistic 3D Tiles.
 ///
 /// This uses Cesium's `createGooglePhotorealistic3DTileset()` API.
@@ -43,11 +88,31 @@ pub fn GooglePhotorealistic3DTiles(
     /// Enable collision detection. Default: true
     #[prop(optional)]
     enable_collision: Option<bool>,
+    /// Declarative style for the tileset's features
+    #[prop(optional, into)]
+    style: Signal<Option<TileStyle>>,
+    /// Called with the feature of this tileset that was clicked
+    #[prop(optional, into)]
+    on_feature_click: Option<FeatureClickCallback>,
+    /// Called when the pointer enters a feature of this tileset, or leaves it (`None`)
+    #[prop(optional, into)]
+    on_feature_hover: Option<FeatureHoverCallback>,
+    /// Color to tint the hovered feature with
+    #[prop(optional, into)]
+    hover_color: JsSignal<Option<Color>>,
+    /// Receives the tileset's load progress, readiness and failed tiles
+    #[prop(optional)]
+    load_state: Option<RwSignal<TilesetLoadState>>,
+    /// Called for every tile that fails to load
+    #[prop(optional, into)]
+    on_tile_failed: Option<UnsyncCallback<TileFailure>>,
 ) -> impl IntoView {
     #[cfg(target_arch = "wasm32")]
     {
         let viewer_context = use_cesium_context()
             .expect("GooglePhotorealistic3DTiles must be inside ViewerContainer");
+        let tileset: JsRwSignal<Option<Cesium3DTileset>> = RwSignal::new_local(None);
+        let generation = LoadGeneration::new();
 
         Effect::new(move |_| {
             viewer_context.with_viewer(|viewer: Viewer| {
@@ -55,6 +120,12 @@ pub fn GooglePhotorealistic3DTiles(
                     "GooglePhotorealistic3DTiles: loading tileset...",
                 ));
 
+                let current = generation.next();
+                if let Some(previous) = tileset.get_untracked() {
+                    viewer.scene().primitives().remove(&previous);
+                    tileset.set(None);
+                }
+
                 // Build API options
                 let mut api_options = GooglePhotorealistic3DTilesApiOptions::default();
                 if let Some(key_signal) = google_api_key {
@@ -78,13 +149,30 @@ pub fn GooglePhotorealistic3DTiles(
 
                 let promise =
                     create_google_photorealistic_3d_tileset(&api_options_js, &tileset_options_js);
-                let scene = viewer.scene();
-                let primitives = scene.primitives();
 
                 wasm_bindgen_futures::spawn_local(async move {
                     match JsFuture::from(promise).await {
-                        Ok(tileset) => {
-                            primitives.add(&tileset);
+                        Ok(loaded) => {
+                            let loaded = loaded.unchecked_into::<Cesium3DTileset>();
+
+                            // Unmounted or superseded while loading
+                            if !generation.is_current(current) {
+                                loaded.destroy();
+                                web_sys::console::log_1(&JsValue::from_str(
+                                    "GooglePhotorealistic3DTiles: discarded tileset that loaded after unmount",
+                                ));
+                                return;
+                            }
+                            let added = viewer_context.with_viewer(|viewer: Viewer| {
+                                viewer.scene().primitives().add(&loaded);
+                            });
+                            if added.is_none() {
+                                loaded.destroy();
+                                return;
+                            }
+
+                            tileset.set(Some(loaded));
+                            viewer_context.request_render();
                             web_sys::console::log_1(&JsValue::from_str(
                                 "GooglePhotorealistic3DTiles: tileset loaded and added to scene",
                             ));
@@ -100,17 +188,21 @@ pub fn GooglePhotorealistic3DTiles(
             });
         });
 
+        bind_tile_style(tileset, style);
+        bind_feature_picking(tileset, on_feature_click, on_feature_hover, hover_color);
+        bind_load_events(tileset, load_state, on_tile_failed);
+
         on_cleanup(move || {
-            // Remove all primitives when component unmounts (removes Google 3D Tiles)
-            if let Some(viewer_ctx) = use_cesium_context() {
-                viewer_ctx.with_viewer(|viewer: Viewer| {
-                    let scene = viewer.scene();
-                    let primitives = scene.primitives();
-                    primitives.remove_all();
+            // Remove only this component's tileset; other primitives stay in the scene
+            generation.invalidate();
+            if let Some(tileset) = tileset.try_get_untracked().flatten() {
+                viewer_context.with_viewer(|viewer: Viewer| {
+                    viewer.scene().primitives().remove(&tileset);
                     web_sys::console::log_1(&JsValue::from_str(
-                        "GooglePhotorealistic3DTiles: all primitives removed from scene",
+                        "GooglePhotorealistic3DTiles: tileset removed from scene",
                     ));
                 });
+                viewer_context.request_render();
             }
         });
     }
@@ -122,6 +214,466 @@ pub fn GooglePhotorealistic3DTiles(
             cache_bytes,
             maximum_cache_overflow_bytes,
             enable_collision,
+            style,
+            on_feature_click,
+            on_feature_hover,
+            hover_color,
+            load_state,
+            on_tile_failed,
         );
     }
 }
+
+/// Component for any 3D Tiles tileset, loaded from a URL or a Cesium Ion asset
+///
+/// Uses `Cesium3DTileset.fromUrl` when `url` is set and `Cesium3DTileset.fromIonAssetId`
+/// otherwise. Changing either reloads the tileset; `show`, `maximum_screen_space_error`,
+/// `model_matrix`, `clipping` and `style` are applied to the loaded tileset without reloading it.
+/// The remaining options are passed to the `Cesium3DTileset` constructor and keep Cesium's
+/// default while unset.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let fly_to_tileset = move |tileset: Cesium3DTileset| {
+///     if let Some(viewer) = use_cesium_context().and_then(|ctx| ctx.viewer_untracked()) {
+///         let _ = viewer.fly_to(&tileset);
+///     }
+/// };
+///
+/// view! {
+///     <ViewerContainer base_layer=false>
+///         <Tileset3D
+///             url="tiles/building/tileset.json".to_string()
+///             maximum_screen_space_error=8.0
+///             on_ready=fly_to_tileset
+///         />
+///     </ViewerContainer>
+/// }
+/// ```
+#[component(transparent)]
+pub fn Tileset3D(
+    /// URL of the tileset's `tileset.json`; takes precedence over `ion_asset_id`
+    #[prop(optional, into)]
+    url: Signal<Option<String>>,
+    /// Cesium Ion asset id of the tileset
+    #[prop(optional, into)]
+    ion_asset_id: Signal<Option<u32>>,
+    /// Whether to show the tileset (default: true)
+    #[prop(optional, into, default = true.into())]
+    show: Signal<bool>,
+    /// Maximum screen space error for level of detail; lower is more detailed (default: 16)
+    #[prop(optional, into)]
+    maximum_screen_space_error: Signal<Option<f64>>,
+    /// Transform applied to the whole tileset, e.g. to place a model in local coordinates
+    /// (default: identity)
+    #[prop(optional, into)]
+    model_matrix: JsSignal<Option<Matrix4>>,
+    /// Clipping planes or polygons cutting into the tileset
+    #[prop(optional, into)]
+    clipping: Signal<Option<Clipping>>,
+    /// Cache size in bytes. Default: 512 MB
+    #[prop(optional)]
+    cache_bytes: Option<u32>,
+    /// Maximum cache overflow in bytes. Default: 512 MB
+    #[prop(optional)]
+    maximum_cache_overflow_bytes: Option<u32>,
+    /// Enable camera collision detection. Default: true
+    #[prop(optional)]
+    enable_collision: Option<bool>,
+    /// Whether the tileset casts and receives shadows. Default: enabled
+    #[prop(optional)]
+    shadows: Option<ShadowMode>,
+    /// Cull tiles whose children are all outside the view. Default: true
+    #[prop(optional)]
+    cull_with_children_bounds: Option<bool>,
+    /// Skip requests for tiles likely to be out of view once the camera stops. Default: true
+    #[prop(optional)]
+    cull_requests_while_moving: Option<bool>,
+    /// How much camera movement skips requests while moving. Default: 60
+    #[prop(optional)]
+    cull_requests_while_moving_multiplier: Option<f64>,
+    /// Keep loading tiles while the tileset is hidden. Default: false
+    #[prop(optional)]
+    preload_when_hidden: Option<bool>,
+    /// Load tiles at the destination of a camera flight while flying. Default: true
+    #[prop(optional)]
+    preload_flight_destinations: Option<bool>,
+    /// Load leaf tiles first. Default: false
+    #[prop(optional)]
+    prefer_leaves: Option<bool>,
+    /// Lower the detail of tiles far away in street-level views. Default: true
+    #[prop(optional)]
+    dynamic_screen_space_error: Option<bool>,
+    /// Density of the fog used for the dynamic screen space error. Default: 2.0e-4
+    #[prop(optional)]
+    dynamic_screen_space_error_density: Option<f64>,
+    /// How strongly the dynamic screen space error lowers detail. Default: 24
+    #[prop(optional)]
+    dynamic_screen_space_error_factor: Option<f64>,
+    /// Fraction of the tileset height where the dynamic screen space error starts. Default: 0.25
+    #[prop(optional)]
+    dynamic_screen_space_error_height_falloff: Option<f64>,
+    /// Load tiles at this fraction of the screen resolution first, between 0 and 0.5.
+    /// Default: 0.3
+    #[prop(optional)]
+    progressive_resolution_height_fraction: Option<f64>,
+    /// Load tiles at the center of the screen first. Default: true
+    #[prop(optional)]
+    foveated_screen_space_error: Option<bool>,
+    /// Size of the center cone that is loaded first, between 0 and 1. Default: 0.1
+    #[prop(optional)]
+    foveated_cone_size: Option<f64>,
+    /// Screen space error relaxation of tiles at the edge of the cone. Default: 0
+    #[prop(optional)]
+    foveated_minimum_screen_space_error_relaxation: Option<f64>,
+    /// Seconds to wait after the camera stops before loading tiles outside the cone.
+    /// Default: 0.2
+    #[prop(optional)]
+    foveated_time_delay: Option<f64>,
+    /// Skip levels of detail while loading. Default: false
+    #[prop(optional)]
+    skip_level_of_detail: Option<bool>,
+    /// Screen space error that must be reached before skipping levels. Default: 1024
+    #[prop(optional)]
+    base_screen_space_error: Option<f64>,
+    /// Multiplier of the screen space error between skipped levels. Default: 16
+    #[prop(optional)]
+    skip_screen_space_error_factor: Option<f64>,
+    /// Minimum number of levels to skip. Default: 1
+    #[prop(optional)]
+    skip_levels: Option<u32>,
+    /// Load only the tiles meeting the screen space error, skipping all levels in between.
+    /// Default: false
+    #[prop(optional)]
+    immediately_load_desired_level_of_detail: Option<bool>,
+    /// Load the siblings of visible tiles while skipping levels. Default: false
+    #[prop(optional)]
+    load_siblings: Option<bool>,
+    /// Cull back faces of the tileset's geometry. Default: true
+    #[prop(optional)]
+    back_face_culling: Option<bool>,
+    /// Keep the outlines of models with `CESIUM_primitive_outline` available. Default: true
+    #[prop(optional)]
+    enable_show_outline: Option<bool>,
+    /// Draw the outlines of models with `CESIUM_primitive_outline`. Default: true
+    #[prop(optional)]
+    show_outline: Option<bool>,
+    /// Show the tileset's credits on screen instead of in the credits lightbox. Default: false
+    #[prop(optional)]
+    show_credits_on_screen: Option<bool>,
+    /// Allow picking features of the tileset. Default: true
+    #[prop(optional)]
+    enable_pick: Option<bool>,
+    /// Declarative style for the tileset's features
+    #[prop(optional, into)]
+    style: Signal<Option<TileStyle>>,
+    /// Called with the feature of this tileset that was clicked
+    #[prop(optional, into)]
+    on_feature_click: Option<FeatureClickCallback>,
+    /// Called when the pointer enters a feature of this tileset, or leaves it (`None`)
+    #[prop(optional, into)]
+    on_feature_hover: Option<FeatureHoverCallback>,
+    /// Color to tint the hovered feature with
+    #[prop(optional, into)]
+    hover_color: JsSignal<Option<Color>>,
+    /// Receives the tileset's load progress, readiness and failed tiles
+    #[prop(optional)]
+    load_state: Option<RwSignal<TilesetLoadState>>,
+    /// Called for every tile that fails to load
+    #[prop(optional, into)]
+    on_tile_failed: Option<UnsyncCallback<TileFailure>>,
+    /// Called with the tileset once it has loaded and been added to the scene
+    #[prop(optional, into)]
+    on_ready: Option<UnsyncCallback<Cesium3DTileset>>,
+    /// Receives the error message when the tileset fails to load, and `None` once it has loaded
+    #[prop(optional)]
+    error: Option<WriteSignal<Option<String>>>,
+) -> impl IntoView {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let viewer_context =
+            use_cesium_context().expect("Tileset3D must be inside ViewerContainer");
+        let tileset: JsRwSignal<Option<Cesium3DTileset>> = RwSignal::new_local(None);
+        let generation = LoadGeneration::new();
+
+        Effect::new(move |_| {
+            let url = url.get();
+            let ion_asset_id = ion_asset_id.get();
+            let Some(viewer) = viewer_context.viewer() else {
+                return;
+            };
+
+            let current = generation.next();
+            if let Some(previous) = tileset.get_untracked() {
+                viewer.scene().primitives().remove(&previous);
+                tileset.set(None);
+            }
+
+            let options = Cesium3DTilesetOptions {
+                cache_bytes,
+                maximum_cache_overflow_bytes,
+                enable_collision,
+                maximum_screen_space_error: maximum_screen_space_error.get_untracked(),
+                shadows,
+                cull_with_children_bounds,
+                cull_requests_while_moving,
+                cull_requests_while_moving_multiplier,
+                preload_when_hidden,
+                preload_flight_destinations,
+                prefer_leaves,
+                dynamic_screen_space_error,
+                dynamic_screen_space_error_density,
+                dynamic_screen_space_error_factor,
+                dynamic_screen_space_error_height_falloff,
+                progressive_resolution_height_fraction,
+                foveated_screen_space_error,
+                foveated_cone_size,
+                foveated_minimum_screen_space_error_relaxation,
+                foveated_time_delay,
+                skip_level_of_detail,
+                base_screen_space_error,
+                skip_screen_space_error_factor,
+                skip_levels,
+                immediately_load_desired_level_of_detail,
+                load_siblings,
+                back_face_culling,
+                enable_show_outline,
+                show_outline,
+                show_credits_on_screen,
+                enable_pick,
+            }
+            .to_js_value();
+            let promise = match (url, ion_asset_id) {
+                (Some(url), _) => Cesium3DTileset::from_url(&url, &options),
+                (None, Some(asset_id)) => Cesium3DTileset::from_ion_asset_id(asset_id, &options),
+                (None, None) => return,
+            };
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = JsFuture::from(promise).await;
+                // Unmounted or superseded while loading
+                let is_stale = !generation.is_current(current);
+                let loaded = match result {
+                    Ok(loaded) => loaded.unchecked_into::<Cesium3DTileset>(),
+                    Err(_) if is_stale => return,
+                    Err(err) => {
+                        web_sys::console::error_2(
+                            &JsValue::from_str("Tileset3D: failed to load:"),
+                            &err,
+                        );
+                        if let Some(error) = error {
+                            error.try_set(Some(CesiumError::from_js(&err).to_string()));
+                        }
+                        return;
+                    }
+                };
+                if is_stale {
+                    loaded.destroy();
+                    return;
+                }
+                let added = viewer_context.with_viewer(|viewer: Viewer| {
+                    viewer.scene().primitives().add(&loaded);
+                });
+                if added.is_none() {
+                    loaded.destroy();
+                    return;
+                }
+
+                tileset.set(Some(loaded.clone()));
+                viewer_context.request_render();
+                if let Some(error) = error {
+                    error.try_set(None);
+                }
+                if let Some(on_ready) = on_ready {
+                    on_ready.run(loaded);
+                }
+            });
+        });
+
+        // Clipping collections are owned by one tileset, so build a fresh one for each change
+        Effect::new(move |_| {
+            let clipping = clipping.get();
+            tileset.with(|tileset| {
+                if let Some(tileset) = tileset {
+                    apply_clipping(tileset, clipping.as_ref());
+                }
+            });
+            viewer_context.request_render();
+        });
+
+        // Keep display properties in sync without reloading the tileset
+        Effect::new(move |_| {
+            let show = show.get();
+            let maximum_screen_space_error = maximum_screen_space_error.get();
+            let model_matrix = model_matrix.get();
+
+            tileset.with(|tileset| {
+                let Some(tileset) = tileset else {
+                    return;
+                };
+                tileset.set_show(show);
+                tileset.set_maximum_screen_space_error(
+                    maximum_screen_space_error.unwrap_or(DEFAULT_MAXIMUM_SCREEN_SPACE_ERROR),
+                );
+                match &model_matrix {
+                    Some(matrix) => tileset.set_model_matrix(matrix),
+                    None => tileset.set_model_matrix(&Matrix4::identity()),
+                }
+            });
+            viewer_context.request_render();
+        });
+
+        bind_tile_style(tileset, style);
+        bind_feature_picking(tileset, on_feature_click, on_feature_hover, hover_color);
+        bind_load_events(tileset, load_state, on_tile_failed);
+
+        on_cleanup(move || {
+            generation.invalidate();
+            if let Some(tileset) = tileset.try_get_untracked().flatten() {
+                viewer_context.with_viewer(|viewer: Viewer| {
+                    viewer.scene().primitives().remove(&tileset);
+                });
+                viewer_context.request_render();
+            }
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = (
+            url,
+            ion_asset_id,
+            show,
+            maximum_screen_space_error,
+            model_matrix,
+            clipping,
+            cache_bytes,
+            maximum_cache_overflow_bytes,
+            enable_collision,
+            shadows,
+            cull_with_children_bounds,
+            cull_requests_while_moving,
+            cull_requests_while_moving_multiplier,
+            preload_when_hidden,
+            preload_flight_destinations,
+            prefer_leaves,
+            dynamic_screen_space_error,
+            dynamic_screen_space_error_density,
+            dynamic_screen_space_error_factor,
+            dynamic_screen_space_error_height_falloff,
+            progressive_resolution_height_fraction,
+            foveated_screen_space_error,
+            foveated_cone_size,
+            foveated_minimum_screen_space_error_relaxation,
+            foveated_time_delay,
+            skip_level_of_detail,
+            base_screen_space_error,
+            skip_screen_space_error_factor,
+            skip_levels,
+            immediately_load_desired_level_of_detail,
+            load_siblings,
+            back_face_culling,
+            enable_show_outline,
+            show_outline,
+            show_credits_on_screen,
+            enable_pick,
+            style,
+            on_feature_click,
+            on_feature_hover,
+            hover_color,
+            load_state,
+            on_tile_failed,
+            on_ready,
+            error,
+        );
+    }
+}
+
+/// Apply `style` to the loaded tileset whenever either changes
+#[cfg(target_arch = "wasm32")]
+fn bind_tile_style(tileset: JsRwSignal<Option<Cesium3DTileset>>, style: Signal<Option<TileStyle>>) {
+    let viewer_context = use_cesium_context().expect("Tileset must be inside ViewerContainer");
+    Effect::new(move |_| {
+        let style = style.get();
+        tileset.with(|tileset| {
+            if let Some(tileset) = tileset {
+                match &style {
+                    Some(style) => tileset.set_style(&style.build()),
+                    None => tileset.set_style(&JsValue::UNDEFINED),
+                }
+            }
+        });
+        viewer_context.request_render();
+    });
+}
+
+/// Report the load events of the loaded tileset through `load_state` and `on_tile_failed`
+#[cfg(target_arch = "wasm32")]
+fn bind_load_events(
+    tileset: JsRwSignal<Option<Cesium3DTileset>>,
+    load_state: Option<RwSignal<TilesetLoadState>>,
+    on_tile_failed: Option<UnsyncCallback<TileFailure>>,
+) {
+    if load_state.is_none() && on_tile_failed.is_none() {
+        return;
+    }
+
+    let listeners: JsStoredValue<Option<LoadListeners>> = StoredValue::new_local(None);
+    let update = move |f: &dyn Fn(&mut TilesetLoadState)| {
+        if let Some(load_state) = load_state {
+            load_state.try_update(|state| f(state));
+        }
+    };
+
+    Effect::new(move |_| {
+        listeners.set_value(None);
+        update(&|state| *state = TilesetLoadState::default());
+
+        tileset.with(|tileset| {
+            let Some(tileset) = tileset else {
+                return;
+            };
+
+            let progress = Closure::wrap(Box::new(move |pending: f64, processing: f64| {
+                update(&|state| {
+                    state.pending_requests = pending as u32;
+                    state.tiles_processing = processing as u32;
+                    if pending > 0.0 || processing > 0.0 {
+                        state.all_tiles_loaded = false;
+                    }
+                });
+            }) as Box<dyn FnMut(f64, f64)>);
+            let all_loaded = Closure::wrap(Box::new(move || {
+                update(&|state| state.all_tiles_loaded = true);
+            }) as Box<dyn FnMut()>);
+            let initial_loaded = Closure::wrap(Box::new(move || {
+                update(&|state| state.initial_tiles_loaded = true);
+            }) as Box<dyn FnMut()>);
+            let failed = Closure::wrap(Box::new(move |error: TileFailedError| {
+                let failure = TileFailure {
+                    url: error.url(),
+                    message: error.message(),
+                };
+                update(&|state| {
+                    state.failed_tiles += 1;
+                    state.last_failure = Some(failure.clone());
+                });
+                if let Some(on_tile_failed) = on_tile_failed {
+                    on_tile_failed.run(failure);
+                }
+            }) as Box<dyn FnMut(TileFailedError)>);
+
+            listeners.set_value(Some(LoadListeners {
+                _progress: EventListener::new(tileset.load_progress(), progress),
+                _all_loaded: EventListener::new(tileset.all_tiles_loaded(), all_loaded),
+                _initial_loaded: EventListener::new(tileset.initial_tiles_loaded(), initial_loaded),
+                _failed: EventListener::new(tileset.tile_failed(), failed),
+            }));
+        });
+    });
+
+    on_cleanup(move || {
+        listeners.try_update_value(Option::take);
+    });
+}
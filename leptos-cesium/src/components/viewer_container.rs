@@ -2,16 +2,24 @@
 
 use leptos::{html::Div, prelude::*};
 
+use crate::bindings::{Clipping, Color, FogOptions, MapProjection, NearFarScalar, SceneMode};
 use crate::components::provide_cesium_context;
+use crate::core::JsSignal;
 
 #[cfg(target_arch = "wasm32")]
-use crate::bindings::{Viewer, set_default_access_token};
+use crate::bindings::{EventListener, Viewer, apply_clipping, set_default_access_token};
+#[cfg(target_arch = "wasm32")]
+use crate::core::JsStoredValue;
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::{JsCast, JsValue};
 #[cfg(target_arch = "wasm32")]
 use web_sys::{HtmlElement, console};
 
+/// Listener of a Cesium event that passes one value, e.g. `trackedEntityChanged`
+#[cfg(target_arch = "wasm32")]
+type ValueListener = EventListener<dyn FnMut(JsValue)>;
+
 /// Minimal Cesium viewer container component.
 ///
 /// This sets up the viewer context for descendants and creates a Cesium Viewer instance.
@@ -26,6 +34,9 @@ use web_sys::{HtmlElement, console};
 /// * `animation` - Whether to show animation widget. Defaults to true.
 /// * `timeline` - Whether to show timeline widget. Defaults to true.
 /// * `base_layer_picker` - Whether to show base layer picker. Defaults to true.
+/// * `base_layer` - Whether to start with Cesium's default Ion imagery as the base layer. Defaults
+///   to true. Set to false for offline deployments and add imagery with `<ImageryLayer>` instead;
+///   this also hides the base layer picker and skips the missing Ion token warning.
 /// * `home_button` - Whether to show home button. Defaults to true.
 /// * `scene_mode_picker` - Whether to show scene mode picker. Defaults to true.
 /// * `navigation_help_button` - Whether to show navigation help button. Defaults to true.
@@ -33,7 +44,36 @@ use web_sys::{HtmlElement, console};
 /// * `info_box` - Whether to show the default InfoBox widget when entities are selected. Defaults to true.
 /// * `selection_indicator` - Whether to show the green selection indicator when entities are selected. Defaults to true.
 /// * `should_animate` - Whether animations should play automatically. Defaults to true. Required for CZML animations.
+/// * `globe_clipping` - Clipping planes or polygons cutting into the globe, e.g. to show tunnels.
+/// * `scene_mode` - 3D globe, 2D map or Columbus view. Defaults to 3D. Changes morph the scene;
+///   read the completed mode back with `use_cesium_context().scene_mode()`.
+/// * `morph_duration` - Seconds a scene mode change takes. Defaults to 2.0.
+/// * `map_projection` - Projection used in 2D and Columbus view, set when the viewer is created.
+///   Defaults to `MapProjection::Geographic`.
+/// * `enable_lighting` - Light the globe by the sun, giving it a night side.
+/// * `depth_test_against_terrain` - Hide primitives and entities behind terrain.
+/// * `base_color` - Globe color where no imagery is loaded.
+/// * `show_ground_atmosphere` - Draw the atmosphere on the globe surface.
+/// * `translucency` - Front face alpha of the globe by camera distance, e.g. to see underground
+///   features. Switching back to `None` turns translucency off again.
+/// * `terrain_exaggeration` - Vertical exaggeration of terrain and 3D Tiles heights.
+/// * `fog` - Fog settings, see `FogOptions`.
+/// * `sky_box` - Whether to show the stars.
+/// * `sky_atmosphere` - Whether to show the atmosphere around the globe's limb.
+/// * `sun` - Whether to show the sun.
+/// * `moon` - Whether to show the moon.
+/// * `high_dynamic_range` - Whether to render in high dynamic range where supported.
+/// * `request_render_mode` - Render only when something changes instead of every frame, saving
+///   power on mostly static scenes. Defaults to false. leptos-cesium components request a frame
+///   whenever they change the scene; call `use_cesium_context().request_render()` after changing
+///   Cesium objects directly.
+/// * `maximum_render_time_change` - In request render mode, simulation seconds after which a
+///   frame is rendered anyway. Defaults to 0.0, rendering on every clock change; use
+///   `f64::INFINITY` to ignore the clock.
 /// * `children` - Child components (entities, data sources, etc.)
+///
+/// The globe and atmosphere props use Cesium's default while `None`, so clearing one restores
+/// the default.
 #[component]
 pub fn ViewerContainer(
     #[prop(optional, into)] ion_token: Signal<Option<String>>,
@@ -43,6 +83,7 @@ pub fn ViewerContainer(
     #[prop(optional, default = true)] animation: bool,
     #[prop(optional, default = true)] timeline: bool,
     #[prop(optional, default = true)] base_layer_picker: bool,
+    #[prop(optional, default = true)] base_layer: bool,
     #[prop(optional, default = true)] home_button: bool,
     #[prop(optional, default = true)] scene_mode_picker: bool,
     #[prop(optional, default = true)] navigation_help_button: bool,
@@ -51,6 +92,24 @@ pub fn ViewerContainer(
     #[prop(optional, default = true)] selection_indicator: bool,
     #[prop(optional, default = true)] should_animate: bool,
     #[prop(optional, into, default = true.into())] globe: Signal<bool>,
+    #[prop(optional, into)] globe_clipping: Signal<Option<Clipping>>,
+    #[prop(optional, into)] scene_mode: Signal<SceneMode>,
+    #[prop(optional, into, default = 2.0.into())] morph_duration: Signal<f64>,
+    #[prop(optional)] map_projection: MapProjection,
+    #[prop(optional, into)] enable_lighting: Signal<Option<bool>>,
+    #[prop(optional, into)] depth_test_against_terrain: Signal<Option<bool>>,
+    #[prop(optional, into)] base_color: JsSignal<Option<Color>>,
+    #[prop(optional, into)] show_ground_atmosphere: Signal<Option<bool>>,
+    #[prop(optional, into)] translucency: Signal<Option<NearFarScalar>>,
+    #[prop(optional, into)] terrain_exaggeration: Signal<Option<f64>>,
+    #[prop(optional, into)] fog: Signal<Option<FogOptions>>,
+    #[prop(optional, into)] sky_box: Signal<Option<bool>>,
+    #[prop(optional, into)] sky_atmosphere: Signal<Option<bool>>,
+    #[prop(optional, into)] sun: Signal<Option<bool>>,
+    #[prop(optional, into)] moon: Signal<Option<bool>>,
+    #[prop(optional, into)] high_dynamic_range: Signal<Option<bool>>,
+    #[prop(optional, into)] request_render_mode: Signal<bool>,
+    #[prop(optional, into)] maximum_render_time_change: Signal<Option<f64>>,
     #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let viewer_context = provide_cesium_context();
@@ -108,7 +167,7 @@ pub fn ViewerContainer(
                     "ViewerContainer: setting Cesium Ion access token.",
                 ));
                 set_default_access_token(&token);
-            } else {
+            } else if base_layer {
                 console::warn_1(&JsValue::from_str(
                     "ViewerContainer: No Cesium Ion access token provided. Some assets may not load.",
                 ));
@@ -133,8 +192,15 @@ pub fn ViewerContainer(
             let _ = js_sys::Reflect::set(
                 &options,
                 &JsValue::from_str("baseLayerPicker"),
-                &JsValue::from_bool(base_layer_picker),
+                &JsValue::from_bool(base_layer_picker && base_layer),
             );
+            if !base_layer {
+                let _ = js_sys::Reflect::set(
+                    &options,
+                    &JsValue::from_str("baseLayer"),
+                    &JsValue::FALSE,
+                );
+            }
             let _ = js_sys::Reflect::set(
                 &options,
                 &JsValue::from_str("homeButton"),
@@ -170,11 +236,25 @@ pub fn ViewerContainer(
                 &JsValue::from_str("shouldAnimate"),
                 &JsValue::from_bool(should_animate),
             );
+            // Start in the requested mode rather than morphing to it
+            let _ = js_sys::Reflect::set(
+                &options,
+                &JsValue::from_str("sceneMode"),
+                &JsValue::from(scene_mode.get_untracked().to_cesium()),
+            );
+            let _ = js_sys::Reflect::set(
+                &options,
+                &JsValue::from_str("mapProjection"),
+                &map_projection.to_js(),
+            );
 
             let viewer = Viewer::new(&element, &options.into());
             console::debug_1(&JsValue::from_str(
                 "ViewerContainer: viewer created; storing in context.",
             ));
+            if let Some(mode) = SceneMode::from_cesium(viewer.scene().mode()) {
+                viewer_context.set_scene_mode(mode);
+            }
             viewer_context.set_viewer(viewer);
 
             // Remove cesium-viewer-bottom
@@ -198,6 +278,7 @@ pub fn ViewerContainer(
                 animation,
                 timeline,
                 base_layer_picker,
+                base_layer,
                 home_button,
                 scene_mode_picker,
                 navigation_help_button,
@@ -205,6 +286,8 @@ pub fn ViewerContainer(
                 info_box,
                 selection_indicator,
                 should_animate,
+                scene_mode,
+                map_projection,
             );
         }
     });
@@ -238,6 +321,96 @@ pub fn ViewerContainer(
         }
     });
 
+    // Keep the tracked entity in sync in both directions: the context signal drives
+    // `viewer.trackedEntity`, and Cesium's own tracking UI updates the signal
+    #[cfg(target_arch = "wasm32")]
+    let tracked_entity_listener: JsStoredValue<Option<ValueListener>> =
+        StoredValue::new_local(None);
+    #[cfg(not(feature = "ssr"))]
+    Effect::new(move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use wasm_bindgen::closure::Closure;
+
+            let listener = viewer_context.viewer().map(|viewer| {
+                let ctx = viewer_context;
+                EventListener::new(
+                    viewer.tracked_entity_changed(),
+                    Closure::wrap(Box::new(move |entity: JsValue| {
+                        ctx.set_tracked_entity_from_js(entity);
+                    }) as Box<dyn FnMut(JsValue)>),
+                )
+            });
+            // Replacing the listener of a previous viewer removes it
+            tracked_entity_listener.set_value(listener);
+        }
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    Effect::new(move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let tracked = viewer_context.tracked_entity();
+            if let Some(viewer) = viewer_context.viewer() {
+                let current = viewer.tracked_entity().map(JsValue::from);
+                if current != tracked.as_ref().map(JsValue::from) {
+                    viewer.set_tracked_entity(tracked.as_ref());
+                }
+            }
+        }
+    });
+
+    // Report the scene mode once a morph completes, whether started here or by the picker
+    #[cfg(target_arch = "wasm32")]
+    let morph_listener: JsStoredValue<Option<EventListener<dyn FnMut()>>> =
+        StoredValue::new_local(None);
+    #[cfg(not(feature = "ssr"))]
+    Effect::new(move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use wasm_bindgen::closure::Closure;
+
+            let listener = viewer_context.viewer().map(|viewer| {
+                let ctx = viewer_context;
+                let scene = viewer.scene();
+                let morphed_scene = viewer.scene();
+                EventListener::new(
+                    scene.morph_complete(),
+                    Closure::wrap(Box::new(move || {
+                        if let Some(mode) = SceneMode::from_cesium(morphed_scene.mode()) {
+                            ctx.set_scene_mode(mode);
+                        }
+                    }) as Box<dyn FnMut()>),
+                )
+            });
+            morph_listener.set_value(listener);
+        }
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    Effect::new(move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let mode = scene_mode.get();
+            if let Some(viewer) = viewer_context.viewer() {
+                let scene = viewer.scene();
+                if SceneMode::from_cesium(scene.mode()) != Some(mode) {
+                    let duration = morph_duration.get_untracked();
+                    match mode {
+                        SceneMode::Scene3D => scene.morph_to_3d(duration),
+                        SceneMode::Scene2D => scene.morph_to_2d(duration),
+                        SceneMode::ColumbusView => scene.morph_to_columbus_view(duration),
+                    }
+                }
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = (scene_mode, morph_duration);
+        }
+    });
+
     // Separate effect to control globe visibility dynamically
     #[cfg(not(feature = "ssr"))]
     Effect::new(move |_| {
@@ -262,6 +435,7 @@ pub fn ViewerContainer(
                     )));
                 }
             });
+            viewer_context.request_render();
         }
 
         #[cfg(not(target_arch = "wasm32"))]
@@ -270,9 +444,170 @@ pub fn ViewerContainer(
         }
     });
 
+    // Render on demand rather than every frame
+    #[cfg(not(feature = "ssr"))]
+    Effect::new(move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let request_render_mode = request_render_mode.get();
+            let maximum_render_time_change = maximum_render_time_change.get();
+            if let Some(viewer) = viewer_context.viewer() {
+                let scene = viewer.scene();
+                scene.set_request_render_mode(request_render_mode);
+                scene.set_maximum_render_time_change(maximum_render_time_change.unwrap_or(0.0));
+                scene.request_render();
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = (request_render_mode, maximum_render_time_change);
+        }
+    });
+
+    // Globe surface settings, with Cesium's defaults for unset props
+    #[cfg(not(feature = "ssr"))]
+    Effect::new(move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let enable_lighting = enable_lighting.get();
+            let depth_test_against_terrain = depth_test_against_terrain.get();
+            let base_color = base_color.get();
+            let show_ground_atmosphere = show_ground_atmosphere.get();
+            let Some(globe) = viewer_context
+                .viewer()
+                .and_then(|viewer| viewer.scene().globe())
+            else {
+                return;
+            };
+
+            globe.set_enable_lighting(enable_lighting.unwrap_or(false));
+            globe.set_depth_test_against_terrain(depth_test_against_terrain.unwrap_or(false));
+            globe.set_base_color(&base_color.unwrap_or_else(Color::blue));
+            globe.set_show_ground_atmosphere(show_ground_atmosphere.unwrap_or(true));
+            viewer_context.request_render();
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = (
+                enable_lighting,
+                depth_test_against_terrain,
+                base_color,
+                show_ground_atmosphere,
+            );
+        }
+    });
+
+    // Globe translucency, left alone until the prop is set; returns whether it was applied
+    #[cfg(not(feature = "ssr"))]
+    Effect::new(move |applied: Option<bool>| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let translucency = translucency.get();
+            let applied = applied.unwrap_or(false);
+            let Some(globe) = viewer_context
+                .viewer()
+                .and_then(|viewer| viewer.scene().globe())
+            else {
+                return applied;
+            };
+            if translucency.is_none() && !applied {
+                return false;
+            }
+
+            let globe_translucency = globe.translucency();
+            globe_translucency.set_enabled(translucency.is_some());
+            globe_translucency.set_front_face_alpha_by_distance(
+                &translucency.map_or(JsValue::UNDEFINED, |alpha| alpha.to_js()),
+            );
+            viewer_context.request_render();
+            translucency.is_some()
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = (translucency, applied);
+            false
+        }
+    });
+
+    // Scene-level atmosphere and rendering settings, with Cesium's defaults for unset props
+    #[cfg(not(feature = "ssr"))]
+    Effect::new(move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let terrain_exaggeration = terrain_exaggeration.get();
+            let fog = fog.get();
+            let sky_box = sky_box.get();
+            let sky_atmosphere = sky_atmosphere.get();
+            let sun = sun.get();
+            let moon = moon.get();
+            let high_dynamic_range = high_dynamic_range.get();
+            let Some(viewer) = viewer_context.viewer() else {
+                return;
+            };
+            let scene = viewer.scene();
+
+            scene.set_vertical_exaggeration(terrain_exaggeration.unwrap_or(1.0));
+            fog.unwrap_or_default().apply(&scene.fog());
+            if let Some(scene_sky_box) = scene.sky_box() {
+                scene_sky_box.set_show(sky_box.unwrap_or(true));
+            }
+            if let Some(scene_sky_atmosphere) = scene.sky_atmosphere() {
+                scene_sky_atmosphere.set_show(sky_atmosphere.unwrap_or(true));
+            }
+            if let Some(scene_sun) = scene.sun() {
+                scene_sun.set_show(sun.unwrap_or(true));
+            }
+            if let Some(scene_moon) = scene.moon() {
+                scene_moon.set_show(moon.unwrap_or(true));
+            }
+            scene.set_high_dynamic_range(high_dynamic_range.unwrap_or(false));
+            scene.request_render();
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = (
+                terrain_exaggeration,
+                fog,
+                sky_box,
+                sky_atmosphere,
+                sun,
+                moon,
+                high_dynamic_range,
+            );
+        }
+    });
+
+    // Clipping collections are owned by the globe, so build a fresh one for each change
+    #[cfg(not(feature = "ssr"))]
+    Effect::new(move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let clipping = globe_clipping.get();
+            if let Some(globe) = viewer_context
+                .viewer()
+                .and_then(|viewer| viewer.scene().globe())
+            {
+                apply_clipping(&globe, clipping.as_ref());
+            }
+            viewer_context.request_render();
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = globe_clipping;
+        }
+    });
+
     on_cleanup(move || {
         #[cfg(target_arch = "wasm32")]
         {
+            // Dropping the listeners removes them from the viewer's events
+            tracked_entity_listener.try_update_value(Option::take);
+            morph_listener.try_update_value(Option::take);
             if let Some(viewer) = viewer_context.viewer_untracked() {
                 console::debug_1(&JsValue::from_str(
                     "ViewerContainer: destroying Cesium viewer on cleanup.",
@@ -291,6 +626,7 @@ pub fn ViewerContainer(
             animation,
             timeline,
             base_layer_picker,
+            base_layer,
             home_button,
             scene_mode_picker,
             navigation_help_button,
@@ -299,6 +635,24 @@ pub fn ViewerContainer(
             selection_indicator,
             should_animate,
             globe,
+            globe_clipping,
+            scene_mode,
+            morph_duration,
+            map_projection,
+            enable_lighting,
+            depth_test_against_terrain,
+            base_color,
+            show_ground_atmosphere,
+            translucency,
+            terrain_exaggeration,
+            fog,
+            sky_box,
+            sky_atmosphere,
+            sun,
+            moon,
+            high_dynamic_range,
+            request_render_mode,
+            maximum_render_time_change,
         );
     }
 
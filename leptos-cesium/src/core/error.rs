@@ -0,0 +1,35 @@
+//! Error type for fallible Cesium operations.
+
+use thiserror::Error;
+
+/// Errors reported by asynchronous or fallible Cesium calls
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CesiumError {
+    /// The viewer (or the part of the scene the call needs) does not exist yet or was destroyed
+    #[error("Cesium viewer is not available")]
+    ViewerUnavailable,
+    /// Cesium threw an exception or rejected a promise
+    #[error("Cesium error: {0}")]
+    Js(String),
+}
+
+#[cfg(target_arch = "wasm32")]
+impl CesiumError {
+    /// Wrap a thrown JavaScript value, keeping its message when it is an `Error`
+    pub fn from_js(err: &wasm_bindgen::JsValue) -> Self {
+        use wasm_bindgen::JsCast;
+
+        let message = match err.dyn_ref::<js_sys::Error>() {
+            Some(err) => String::from(err.message()),
+            None => err.as_string().unwrap_or_else(|| format!("{:?}", err)),
+        };
+        CesiumError::Js(message)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl From<wasm_bindgen::JsValue> for CesiumError {
+    fn from(err: wasm_bindgen::JsValue) -> Self {
+        CesiumError::from_js(&err)
+    }
+}
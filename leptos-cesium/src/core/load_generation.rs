@@ -0,0 +1,31 @@
+use leptos::prelude::*;
+
+/// Tracks the latest of a series of asynchronous loads so results of superseded loads can be
+/// dropped
+///
+/// Each load takes a token from [`LoadGeneration::next`] before awaiting and checks it with
+/// [`LoadGeneration::is_current`] afterwards.
+#[derive(Clone, Copy)]
+pub(crate) struct LoadGeneration(StoredValue<u64>);
+
+impl LoadGeneration {
+    pub(crate) fn new() -> Self {
+        Self(StoredValue::new(0))
+    }
+
+    /// Start a new load, superseding every earlier one
+    pub(crate) fn next(self) -> u64 {
+        self.invalidate();
+        self.0.get_value()
+    }
+
+    /// Whether `token` belongs to the latest load and the owner has not been disposed
+    pub(crate) fn is_current(self, token: u64) -> bool {
+        self.0.try_get_value() == Some(token)
+    }
+
+    /// Supersede every load started so far
+    pub(crate) fn invalidate(self) {
+        self.0.try_update_value(|generation| *generation += 1);
+    }
+}
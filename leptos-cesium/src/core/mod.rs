@@ -1,7 +1,13 @@
 //! Core utilities for interacting with Cesium inside Leptos components.
 
+pub mod error;
 pub mod js_signals;
+#[cfg(target_arch = "wasm32")]
+mod load_generation;
 pub mod thread_safe_jsvalue;
 
+pub use error::*;
 pub use js_signals::*;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use load_generation::LoadGeneration;
 pub use thread_safe_jsvalue::*;
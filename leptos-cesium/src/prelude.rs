@@ -8,12 +8,20 @@ pub use crate::components::*;
 
 // Re-export common bindings (selective to avoid conflicts)
 pub use crate::bindings::{
-    BoundingSphere, Cartesian2, Cartesian3, CheckerboardMaterialProperty, CheckerboardOptions,
-    Color, HeadingPitchRange, HeadingPitchRoll, Material, PolygonHierarchy,
-    PolylineGlowMaterialProperty, PolylineGlowOptions, Rectangle, StripeMaterialProperty,
-    StripeOptions, Viewer,
+    BingMapsStyle, BoundingSphere, CameraEventType, CameraInput, CameraView, Cartesian2,
+    Cartesian3, Cartographic, Cesium3DTileFeature, Cesium3DTileset, CheckerboardMaterialProperty,
+    CheckerboardOptions, Clipping, ClippingPlaneOptions, ClippingPlanes, ClippingPolygons, Cluster,
+    ClusterOptions, Color, EasingFunction, FlightOutcome, FogOptions, HeadingPitchRange,
+    HeadingPitchRoll, ImageryProvider, KeyboardEventModifier, MapProjection, Material, Matrix4,
+    NearFarScalar, PickResult, PolygonHierarchy, PolylineGlowMaterialProperty, PolylineGlowOptions,
+    Rectangle, SceneMode, ScreenshotFormat, ScreenshotOptions, SelectionStyle, ShadowMode,
+    SplitDirection, StripeMaterialProperty, StripeOptions, TerrainProvider, TileMapServiceOptions,
+    TileStyle, TilingScheme, UrlTemplateOptions, Viewer,
 };
 
+#[cfg(target_arch = "wasm32")]
+pub use crate::bindings::capture_screenshot;
+
 // Re-export math utilities
 pub use crate::bindings::math::{to_degrees, to_radians};
 